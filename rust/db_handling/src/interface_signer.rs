@@ -24,6 +24,7 @@ use definitions::{
     },
     network_specs::{NetworkSpecs, ValidCurrentVerifier},
     qr_transfers::ContentLoadTypes,
+    redacted::Redacted,
     users::AddressDetails,
 };
 use qrcode_static::{png_qr_from_string, DataType};
@@ -786,7 +787,7 @@ pub const WORD_LENGTH: usize = 8;
 #[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
 pub struct SeedDraft {
     /// User-entered word part.
-    user_input: String,
+    user_input: Redacted<String>,
 
     /// Already completed `bip39` words.
     saved: Vec<SeedElement>,
@@ -794,19 +795,19 @@ pub struct SeedDraft {
 
 /// Zeroizeable wrapper around complete `bip39` word entered by user.
 #[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
-struct SeedElement(String);
+struct SeedElement(Redacted<String>);
 
 impl SeedElement {
     /// Make `SeedElement` from checked `bip39` word.
     fn from_checked_str(word: &str) -> Self {
         let mut new = String::with_capacity(WORD_LENGTH);
         new.push_str(word);
-        SeedElement(new)
+        SeedElement(Redacted::new(new))
     }
 
     /// Get `bip39` word from the `SeedElement`.
     fn word(&self) -> &str {
-        &self.0
+        self.0.expose()
     }
 }
 
@@ -814,7 +815,7 @@ impl SeedDraft {
     /// Start new `SeedDraft`
     pub fn initiate() -> Self {
         Self {
-            user_input: String::with_capacity(WORD_LENGTH), // capacity corresponds to maximum word length in `bip39` standard;
+            user_input: Redacted::new(String::with_capacity(WORD_LENGTH)), // capacity corresponds to maximum word length in `bip39` standard;
             saved: Vec::with_capacity(BIP_CAP), // capacity corresponds to maximum word count in `bip39` standard; set here to avoid reallocation;
         }
     }
@@ -830,7 +831,7 @@ impl SeedDraft {
                 // if there are words in draft, remove the last one
                 self.remove_last();
                 // restore the user input to empty one
-                self.user_input.clear();
+                self.user_input.expose_mut().clear();
             } else {
                 let user_text = user_text.trim_start();
 
@@ -839,16 +840,16 @@ impl SeedDraft {
                 if user_text.ends_with(' ') {
                     let word = user_text.trim();
                     if self.added(word, None) {
-                        self.user_input.clear() // added the word successfully, clear `user_input`
+                        self.user_input.expose_mut().clear() // added the word successfully, clear `user_input`
                     } else if !guess(word).is_empty() {
-                        self.user_input = String::from(word) // did not add the word, there are still possible variants, keep trimmed `user_input`
+                        self.user_input = Redacted::new(String::from(word)) // did not add the word, there are still possible variants, keep trimmed `user_input`
                     }
                 } else if !guess(user_text).is_empty() {
-                    self.user_input = String::from(user_text)
+                    self.user_input = Redacted::new(String::from(user_text))
                 }
             }
         } else {
-            self.user_input.clear()
+            self.user_input.expose_mut().clear()
         }
     }
 
@@ -884,7 +885,7 @@ impl SeedDraft {
                     }
                     None => self.saved.push(new), // no position, add word at the end of the list
                 }
-                self.user_input.clear();
+                self.user_input.expose_mut().clear();
                 true
             } else {
                 false
@@ -937,6 +938,6 @@ impl SeedDraft {
 
     /// Output the user input back into user interface.
     pub fn user_input(&self) -> &str {
-        &self.user_input
+        self.user_input.expose()
     }
 }
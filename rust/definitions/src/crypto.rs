@@ -95,6 +95,50 @@ impl Encryption {
             Encryption::Ecdsa => String::from("ecdsa"),
         }
     }
+
+    /// Length of the public key in bytes for the encryption algorithm
+    pub fn public_key_len(&self) -> usize {
+        match &self {
+            Encryption::Ed25519 | Encryption::Sr25519 => 32,
+            Encryption::Ecdsa => 33,
+        }
+    }
+
+    /// Length of the signature in bytes for the encryption algorithm
+    ///
+    /// `Ecdsa` signature includes recovery byte.
+    pub fn signature_len(&self) -> usize {
+        match &self {
+            Encryption::Ed25519 | Encryption::Sr25519 => 64,
+            Encryption::Ecdsa => 65,
+        }
+    }
+
+    /// Check if the signature produced within one encryption algorithm could
+    /// be verified within the other one
+    ///
+    /// All currently supported algorithms use different curves or signing
+    /// schemes, so only the identical algorithms are compatible.
+    pub fn signature_compatible(&self, other: &Encryption) -> bool {
+        match (self, other) {
+            (Encryption::Ed25519, Encryption::Ed25519)
+            | (Encryption::Sr25519, Encryption::Sr25519)
+            | (Encryption::Ecdsa, Encryption::Ecdsa) => true,
+            (Encryption::Ed25519, _) | (Encryption::Sr25519, _) | (Encryption::Ecdsa, _) => false,
+        }
+    }
+
+    /// Check if the encryption algorithm could be used for signing on the
+    /// device
+    ///
+    /// All currently supported algorithms are signable. Algorithms that are
+    /// added for watch-only accounts before signing is implemented for them
+    /// are not.
+    pub fn is_signable(&self) -> bool {
+        match &self {
+            Encryption::Ed25519 | Encryption::Sr25519 | Encryption::Ecdsa => true,
+        }
+    }
 }
 
 /// Data sufficient to generate signed update  
@@ -175,3 +219,36 @@ impl SufficientCrypto {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_and_signature_lengths() {
+        assert_eq!(Encryption::Ed25519.public_key_len(), 32);
+        assert_eq!(Encryption::Sr25519.public_key_len(), 32);
+        assert_eq!(Encryption::Ecdsa.public_key_len(), 33);
+        assert_eq!(Encryption::Ed25519.signature_len(), 64);
+        assert_eq!(Encryption::Sr25519.signature_len(), 64);
+        assert_eq!(Encryption::Ecdsa.signature_len(), 65);
+    }
+
+    #[test]
+    fn signature_compatibility() {
+        let all = [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa];
+        for a in all.iter() {
+            for b in all.iter() {
+                assert_eq!(a.signature_compatible(b), a == b, "{:?} and {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn signable_encryptions() {
+        for encryption in [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa] {
+            assert!(encryption.is_signable(), "{:?}", encryption);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use hex::FromHexError;
-use sp_core::H256;
+use sp_core::{crypto::PublicError, H256};
 #[cfg(feature = "test")]
 use variant_count::VariantCount;
 
@@ -24,11 +24,190 @@ pub enum Error {
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
+    /// Base58 address could not be decoded.
+    ///
+    /// Associated error content is
+    /// [`PublicError`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/enum.PublicError.html).
+    #[error("Unable to decode base58 address: {}", format!("{:?}", .0))]
+    Base58Decoding(PublicError),
+
+    /// Base58 string is too long to be decoded into a payload of acceptable
+    /// length.
+    #[error("Base58 address is too long.")]
+    Base58AddressTooLong,
+
+    /// Bytes could not be interpreted as base58 address prefix.
+    #[error("Invalid base58 prefix bytes.")]
+    InvalidBase58PrefixBytes,
+
+    /// Base58 prefix is reserved or exceeds the two-byte prefix range.
+    #[error("Base58 prefix {0} could not be used for addresses.")]
+    InvalidBase58Prefix(u16),
+
+    /// Base58 prefix is not known for network with this genesis hash.
+    #[error("Base58 prefix is not known for network with genesis hash {}.", hex::encode(.0))]
+    UnknownNetworkGenesisHash(H256),
+
+    /// Public key in base58 address has length not matching the expected
+    /// encryption.
+    #[error(
+        "Base58 address public key length {public_len} does not match expected encryption {}.",
+        expected.show()
+    )]
+    Base58EncryptionMismatch {
+        expected: Encryption,
+        public_len: usize,
+    },
+
+    /// `MultiSigner` variant does not match the expected encryption.
+    #[error(
+        "Public key of encryption {} does not match expected encryption {}.",
+        got.show(),
+        expected.show()
+    )]
+    MultiSignerEncryptionMismatch {
+        expected: Encryption,
+        got: Encryption,
+    },
+
+    /// Base58 string is not an account index address, or the index does not
+    /// fit into `u32`.
+    #[error("Invalid account index address.")]
+    InvalidAccountIndex,
+
+    /// Base58 address prefix differs from the expected one.
+    #[error("Base58 address has prefix {got}, expected {expected}.")]
+    UnexpectedPrefix { expected: u16, got: u16 },
+
+    /// Ethereum address was found where base58 address with network prefix
+    /// was expected.
+    #[error("Ethereum address has no base58 prefix.")]
+    UnexpectedEthereumAddress,
+
+    /// Ethereum address is not 20 bytes long.
+    #[error("Ethereum address must be 20 bytes long.")]
+    WrongEthereumAddressLength,
+
+    /// Ethereum address in mixed case has invalid
+    /// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum.
+    #[error("Ethereum address checksum is invalid.")]
+    EthereumChecksumMismatch,
+
+    /// Identicon could not be generated.
+    #[error("Unable to generate identicon.")]
+    IdenticonGeneration,
+
+    /// Identicon could not be produced in the requested format.
+    #[error("Identicon format is not supported.")]
+    UnsupportedIdenticonFormat,
+
+    /// PNG image could not be decoded.
+    ///
+    /// Associated data is the decoding error description.
+    #[error("Unable to decode PNG image: {0}")]
+    PngDecoding(String),
+
+    /// PNG image could not be encoded.
+    ///
+    /// Associated data is the encoding error description.
+    #[error("Unable to encode PNG image: {0}")]
+    PngEncoding(String),
+
+    /// Derivation path could not be parsed.
+    ///
+    /// Path must consist of soft (`/`) and hard (`//`) junctions with
+    /// non-empty names. Password (`///`) is not part of the derivation path.
+    #[error("Invalid derivation path.")]
+    InvalidDerivationPath,
+
+    /// Seed phrase word count is not one of BIP39 word counts, 12, 15, 18, 21
+    /// or 24.
+    #[error("Seed phrase has unsupported number of words {0}.")]
+    InvalidMnemonic(usize),
+
+    /// Secret URI could not be parsed or the key pair could not be derived
+    /// from it.
+    #[error("Invalid secret URI.")]
+    InvalidSecretUri,
+
+    /// Key pair could not be derived from the seed.
+    ///
+    /// Seed length is not suitable for the encryption algorithm, or the path
+    /// contains soft junctions and the encryption supports only hard ones.
+    #[error("Unable to derive key pair from the seed.")]
+    KeyDerivation,
+
+    /// Polkadot.js account export JSON could not be parsed, or has no
+    /// address or encoding information.
+    ///
+    /// Associated data is the error description.
+    #[error("Invalid polkadot.js account export: {0}")]
+    InvalidPolkadotJsExport(String),
+
+    /// Polkadot.js account export has unsupported key encoding scheme.
+    ///
+    /// Ethereum accounts are exported with Ethereum address only, and the
+    /// public key could not be found without decrypting the secret.
+    #[error("Unsupported polkadot.js account encoding {0}.")]
+    UnsupportedPolkadotJsEncoding(String),
+
+    /// CAIP-10 account identifier is not `namespace:reference:address` with
+    /// decimal chain id as reference.
+    #[error("Invalid CAIP-10 account identifier.")]
+    InvalidCaip10,
+
+    /// Payment URI is not a valid
+    /// [EIP-681](https://eips.ethereum.org/EIPS/eip-681) `ethereum:` URI.
+    #[error("Invalid EIP-681 payment URI.")]
+    InvalidEip681,
+
+    /// CAIP-10 account identifier namespace is not supported.
+    ///
+    /// Only `eip155` namespace is supported.
+    #[error("Unsupported CAIP-10 namespace {0}, expected eip155.")]
+    UnsupportedCaip10Namespace(String),
+
+    /// Compressed `Ecdsa` public key starts with byte other than `0x02` or
+    /// `0x03`.
+    #[error("Compressed public key has invalid prefix byte {0:#04x}.")]
+    InvalidPublicKeyPoint(u8),
+
+    /// Public key has correct length, but is not a valid point on the curve.
+    #[error("Public key is not a valid curve point.")]
+    PublicKeyNotOnCurve,
+
+    /// Ethereum address was requested for public key of encryption algorithm
+    /// not based on secp256k1 curve.
+    #[error("Ethereum address is available only for ecdsa public keys.")]
+    NotSecp256k1Key,
+
+    /// Signed message blob is too short to contain 65-byte signature.
+    #[error("Signed message is too short to contain a signature.")]
+    SignedMessageTooShort,
+
+    /// Verifier record starts with unknown public key variant tag.
+    #[error("Verifier record has unknown encryption tag {0}.")]
+    UnknownVerifierEncryption(u8),
+
+    /// Decoded verifier record has public key and encryption that do not
+    /// match.
+    #[error("Verifier record encryption does not match the public key.")]
+    VerifierEncryptionMismatch,
+
+    /// Hash or other fixed-size value has unexpected length.
+    #[error("Expected {expected} bytes, got {got}.")]
+    WrongHashLength { expected: usize, got: usize },
+
     /// Received public key length is different from the one expected for
     /// given encryption algorithm.
     #[error("Public key length does not match the encryption.")]
     WrongPublicKeyLength,
 
+    /// Received signature length is different from the one expected for
+    /// given encryption algorithm.
+    #[error("Signature length does not match the encryption.")]
+    WrongSignatureLength,
+
     /// `Error` when there is encryption mismatch between one of
     /// [`NetworkSpecsKey`] in `network_id` field of [`AddressDetails`] and the
     /// `encryption` field of [`AddressDetails`]
@@ -88,6 +267,45 @@ pub enum Error {
         genesis_hash: H256,
     },
 
+    /// Byte reader got request for more bytes than there are left.
+    #[error("Unexpected end of data: requested {requested} bytes, {left} left.")]
+    UnexpectedEndOfData { requested: usize, left: usize },
+
+    /// Byte reader has bytes left after all expected data was read.
+    #[error("Data has {left} unexpected trailing bytes.")]
+    TrailingBytes { left: usize },
+
+    /// Multi-frame QR code frame is too short to contain the frame header, or
+    /// has unexpected prelude.
+    #[error("Multi-frame QR code frame has invalid header.")]
+    InvalidQrFrame,
+
+    /// Frame with given index is absent in the set of frames for multi-frame
+    /// QR code.
+    #[error("Multi-frame QR code frame with index {index} is missing.")]
+    MissingFrame { index: u16 },
+
+    /// Frames in the set for multi-frame QR code disagree on the total number
+    /// of frames, or the set contains frames that are duplicated or out of
+    /// announced range.
+    #[error("Multi-frame QR code frames are inconsistent with the announced frame count.")]
+    FrameCountMismatch,
+
+    /// Multi-frame QR code frame size is too small to fit any data after the
+    /// frame header.
+    #[error("Multi-frame QR code frame size {max_frame_bytes} leaves no space for data.")]
+    QrFrameTooSmall { max_frame_bytes: usize },
+
+    /// Payload needs more multi-frame QR code frames than could be announced
+    /// in the frame header.
+    #[error("Payload of {len} bytes needs too many multi-frame QR code frames.")]
+    TooManyQrFrames { len: usize },
+
+    /// Payload does not fit into QR code of the largest version 40 with given
+    /// error correction level.
+    #[error("Payload of {len} bytes does not fit into QR code.")]
+    PayloadTooLargeForQr { len: usize },
+
     #[error(transparent)]
     MetadataError(#[from] MetadataError),
 
@@ -0,0 +1,799 @@
+//! Ethereum address helpers
+//!
+//! Address derivation from `Ecdsa` keys, EIP-55 checksums, CAIP-10 and EIP-681
+//! parsing, contract address computation, and EIP-712 domain hashing.
+
+use hex;
+#[cfg(feature = "signer")]
+use sp_core::{ecdsa, Pair};
+use sp_core::{keccak_256, H160, H256};
+use std::str::FromStr;
+use subtle::ConstantTimeEq;
+#[cfg(feature = "signer")]
+use zeroize::Zeroize;
+
+use crate::error::{Error, Result};
+use crate::helpers::Hex0x;
+
+/// Get Ethereum address from raw 20 bytes
+pub fn eth_address_from_bytes(bytes: &[u8; 20]) -> H160 {
+    H160(*bytes)
+}
+
+/// Print Ethereum address with `0x` prefix, with
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum if `checksummed`
+/// is `true`, in lowercase otherwise
+pub fn eth_address_display(address: &H160, checksummed: bool) -> String {
+    if checksummed {
+        checksum_eth_address(address)
+    } else {
+        format!("{}{}", Hex0x::PREFIX, hex::encode(address))
+    }
+}
+
+/// Print Ethereum address with [EIP-55](https://eips.ethereum.org/EIPS/eip-55)
+/// checksum, with `0x` prefix
+pub fn checksum_eth_address(address: &H160) -> String {
+    let mut out = [0; 42];
+    eip55_into(address, &mut out);
+    checksummed_to_string(out)
+}
+
+/// Print a set of Ethereum addresses with
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum, with `0x` prefix
+///
+/// Same as [`checksum_eth_address`] for each element, in the same order, with
+/// the buffer reused between the elements.
+pub fn checksum_eth_addresses(addresses: &[H160]) -> Vec<String> {
+    let mut out = [0; 42];
+    addresses
+        .iter()
+        .map(|address| {
+            eip55_into(address, &mut out);
+            checksummed_to_string(out)
+        })
+        .collect()
+}
+
+/// Write Ethereum address with [EIP-55](https://eips.ethereum.org/EIPS/eip-55)
+/// checksum, with `0x` prefix, into fixed buffer as ASCII symbols
+pub fn eip55_into(address: &H160, out: &mut [u8; 42]) {
+    out[..2].copy_from_slice(Hex0x::PREFIX.as_bytes());
+    let lowercase = &mut out[2..];
+    hex::encode_to_slice(address.as_bytes(), lowercase)
+        .expect("buffer length is exactly twice the address length");
+    let hash = keccak_256(lowercase);
+    for (i, c) in lowercase.iter_mut().enumerate() {
+        // each symbol of address has corresponding half-byte in hash
+        let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+        if nibble >= 8 {
+            c.make_ascii_uppercase()
+        }
+    }
+}
+
+/// Check if `0x`-prefixed mixed case Ethereum address has valid
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum
+///
+/// Address in lowercase or in uppercase has no checksum to check, so it is
+/// not valid for this function, same as malformed input. Check is done in
+/// fixed buffers, without allocations.
+pub fn is_valid_eip55(address: &str) -> bool {
+    let body = match address.strip_prefix(Hex0x::PREFIX) {
+        Some(a) => a,
+        None => return false,
+    };
+    let has_lowercase = body.bytes().any(|c| c.is_ascii_lowercase());
+    let has_uppercase = body.bytes().any(|c| c.is_ascii_uppercase());
+    if !(has_lowercase && has_uppercase) {
+        return false;
+    }
+    let mut decoded = [0; 20];
+    if hex::decode_to_slice(body, &mut decoded).is_err() {
+        return false;
+    }
+    let mut checksummed = [0; 42];
+    eip55_into(&H160(decoded), &mut checksummed);
+    checksummed == address.as_bytes()
+}
+
+/// Make `String` from [`eip55_into`] output
+fn checksummed_to_string(checksummed: [u8; 42]) -> String {
+    String::from_utf8(checksummed.to_vec()).expect("checksummed address is always ASCII")
+}
+
+/// Print shortened Ethereum address with
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum, with `0x` prefix
+///
+/// Keeps `head` first and `tail` last address symbols, not counting the `0x`
+/// prefix, with `...` in between. Checksum is calculated for the full
+/// address, so the visible symbols have the same case as in the full
+/// checksummed address. If nothing would be cut out, full checksummed address
+/// is returned.
+pub fn shorten_eth_address(address: &H160, head: usize, tail: usize) -> String {
+    let full = checksum_eth_address(address);
+    let body = Hex0x::strip(&full);
+    if head.saturating_add(tail) >= body.len() {
+        return full;
+    }
+    format!(
+        "{}{}...{}",
+        Hex0x::PREFIX,
+        &body[..head],
+        &body[body.len() - tail..]
+    )
+}
+
+/// Get raw 20 bytes of Ethereum address from hexadecimal `&str`
+///
+/// `0x` prefix is optional. Addresses in lowercase or in uppercase are
+/// accepted as is, addresses in mixed case must have valid
+/// [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum.
+pub fn normalize_eth_address(address: &str) -> Result<H160> {
+    let body = Hex0x::strip(address);
+    let decoded = hex::decode(body)?;
+    if decoded.len() != 20 {
+        return Err(Error::WrongEthereumAddressLength);
+    }
+    let normalized = H160::from_slice(&decoded);
+    let has_lowercase = body.chars().any(|c| c.is_ascii_lowercase());
+    let has_uppercase = body.chars().any(|c| c.is_ascii_uppercase());
+    if has_lowercase && has_uppercase && checksum_eth_address(&normalized)[2..] != *body {
+        return Err(Error::EthereumChecksumMismatch);
+    }
+    Ok(normalized)
+}
+
+/// Check if two Ethereum address strings represent same address
+///
+/// Both addresses are normalized with [`normalize_eth_address`], so the
+/// checksum and the `0x` prefix do not affect the comparison.
+pub fn eth_addr_eq(a: &str, b: &str) -> Result<bool> {
+    Ok(normalize_eth_address(a)? == normalize_eth_address(b)?)
+}
+
+/// Check if two Ethereum address strings represent same address, comparing
+/// raw address bytes in constant time
+///
+/// Same as [`eth_addr_eq`], but the time of comparison does not depend on how
+/// many leading bytes of the addresses match.
+pub fn eth_addr_eq_ct(a: &str, b: &str) -> Result<bool> {
+    let a = normalize_eth_address(a)?;
+    let b = normalize_eth_address(b)?;
+    Ok(a.as_bytes().ct_eq(b.as_bytes()).into())
+}
+
+/// Get Ethereum address from `Ecdsa` public key
+///
+/// Ethereum address is the last 20 bytes of `keccak256` hash of the
+/// uncompressed public key without the `0x04` tag byte.
+#[cfg(feature = "signer")]
+pub fn ecdsa_public_to_eth_address(public: &ecdsa::Public) -> Result<H160> {
+    let uncompressed = libsecp256k1::PublicKey::parse_compressed(&public.0)
+        .map_err(|_| Error::PublicKeyNotOnCurve)?
+        .serialize();
+    Ok(H160::from_slice(&keccak_256(&uncompressed[1..])[12..]))
+}
+
+/// Print Ethereum address of `Ecdsa` public key in storage format, as
+/// lowercase hexadecimal string without `0x` prefix
+///
+/// This format is kept stable for stored records, use
+/// [`checksum_eth_address`] for display.
+#[cfg(feature = "signer")]
+pub fn eth_address_legacy_string(public: &ecdsa::Public) -> Result<String> {
+    Ok(hex::encode(ecdsa_public_to_eth_address(public)?))
+}
+
+/// Get Ethereum addresses for a set of `Ecdsa` public keys, in the same
+/// order
+///
+/// Same as [`ecdsa_public_to_eth_address`] for each element. Fails if any of
+/// the public keys is not a valid curve point.
+///
+/// This is a convenience wrapper only, with no speedup over converting the
+/// keys one by one: `libsecp256k1` uses static precomputed tables, and there
+/// is no per-call context to share between the keys.
+#[cfg(feature = "signer")]
+pub fn eth_addresses_batch(publics: &[ecdsa::Public]) -> Result<Vec<H160>> {
+    publics.iter().map(ecdsa_public_to_eth_address).collect()
+}
+
+/// Get Ethereum address for raw `Ecdsa` secret key
+///
+/// `secret` is zeroized before the function returns, both on success and on
+/// error. Key pair is dropped before the function returns, only the address
+/// is kept.
+#[cfg(feature = "signer")]
+pub fn eth_address_from_secret(secret: &mut [u8; 32]) -> Result<H160> {
+    let pair = ecdsa::Pair::from_seed_slice(&secret[..]);
+    secret.zeroize();
+    let public = pair.map_err(|_| Error::KeyDerivation)?.public();
+    ecdsa_public_to_eth_address(&public)
+}
+
+/// Get Ethereum address for secret URI
+///
+/// Secret URI is parsed and the `Ecdsa` key pair is derived same as for any
+/// other `Ecdsa` address in Signer. Key pair is dropped before the function
+/// returns, only the address is kept.
+#[cfg(feature = "signer")]
+pub fn eth_address_from_secret_uri(uri: &str) -> Result<H160> {
+    let public = ecdsa::Pair::from_string(uri, None)
+        .map_err(|_| Error::InvalidSecretUri)?
+        .public();
+    ecdsa_public_to_eth_address(&public)
+}
+
+/// Get address of Ethereum contract deployed with `CREATE` by `deployer`
+/// with given nonce
+///
+/// Contract address is the last 20 bytes of `keccak256` hash of RLP-encoded
+/// list of deployer address and nonce.
+pub fn compute_create_address(deployer: &H160, nonce: u64) -> H160 {
+    // list prefix, address prefix, address, nonce prefix and up to 8 nonce bytes
+    let mut rlp = Vec::with_capacity(31);
+    rlp.push(0);
+    rlp.push(0x80 + 20);
+    rlp.extend_from_slice(deployer.as_bytes());
+    match nonce {
+        0 => rlp.push(0x80),
+        1..=0x7f => rlp.push(nonce as u8),
+        _ => {
+            let bytes = nonce.to_be_bytes();
+            let significant = &bytes[(nonce.leading_zeros() / 8) as usize..];
+            rlp.push(0x80 + significant.len() as u8);
+            rlp.extend_from_slice(significant);
+        }
+    }
+    // list payload is shorter than 56 bytes
+    rlp[0] = 0xc0 + (rlp.len() - 1) as u8;
+    H160::from_slice(&keccak_256(&rlp)[12..])
+}
+
+/// Get address of Ethereum contract deployed with `CREATE2` by `deployer`,
+/// see [EIP-1014](https://eips.ethereum.org/EIPS/eip-1014)
+///
+/// Contract address is the last 20 bytes of `keccak256` hash of `0xff`
+/// followed by deployer address, salt and `keccak256` hash of init code.
+pub fn compute_create2_address(deployer: &H160, salt: &[u8; 32], init_code_hash: &H256) -> H160 {
+    let mut preimage = [0; 85];
+    preimage[0] = 0xff;
+    preimage[1..21].copy_from_slice(deployer.as_bytes());
+    preimage[21..53].copy_from_slice(salt);
+    preimage[53..].copy_from_slice(init_code_hash.as_bytes());
+    H160::from_slice(&keccak_256(&preimage)[12..])
+}
+
+/// Parse [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/master/CAIPs/caip-10.md)
+/// account identifier for Ethereum address, `eip155:<chain id>:<address>`,
+/// into chain id and address
+///
+/// Address is parsed with [`normalize_eth_address`].
+pub fn parse_caip10(caip10: &str) -> Result<(u64, H160)> {
+    let mut parts = caip10.splitn(3, ':');
+    let (namespace, chain_id, address) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(namespace), Some(chain_id), Some(address)) => (namespace, chain_id, address),
+        _ => return Err(Error::InvalidCaip10),
+    };
+    if namespace != "eip155" {
+        return Err(Error::UnsupportedCaip10Namespace(namespace.to_string()));
+    }
+    if chain_id.is_empty() || !chain_id.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::InvalidCaip10);
+    }
+    let chain_id = chain_id.parse::<u64>().map_err(|_| Error::InvalidCaip10)?;
+    Ok((chain_id, normalize_eth_address(address)?))
+}
+
+/// [EIP-681](https://eips.ethereum.org/EIPS/eip-681) payment request
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip681 {
+    /// Target address
+    pub address: H160,
+
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain id, if
+    /// specified
+    pub chain_id: Option<u64>,
+
+    /// Contract function to call, if specified
+    pub function_name: Option<String>,
+
+    /// Query parameters as key-value pairs, in order of appearance
+    pub parameters: Vec<(String, String)>,
+}
+
+/// Parse [EIP-681](https://eips.ethereum.org/EIPS/eip-681) payment URI,
+/// `ethereum:[pay-]<address>[@<chain id>][/<function name>][?<parameters>]`
+///
+/// Address is parsed with [`normalize_eth_address`], ENS names are not
+/// supported. Parameter values are kept as is, without percent-decoding.
+pub fn parse_eip681(uri: &str) -> Result<Eip681> {
+    let body = uri.strip_prefix("ethereum:").ok_or(Error::InvalidEip681)?;
+    let body = body.strip_prefix("pay-").unwrap_or(body);
+    let (target, query) = match body.split_once('?') {
+        Some((target, query)) => (target, Some(query)),
+        None => (body, None),
+    };
+    let (target, function_name) = match target.split_once('/') {
+        Some((target, function_name)) if !function_name.is_empty() => {
+            (target, Some(function_name.to_string()))
+        }
+        Some(_) => return Err(Error::InvalidEip681),
+        None => (target, None),
+    };
+    let (address, chain_id) = match target.split_once('@') {
+        Some((address, chain_id)) => {
+            if chain_id.is_empty() || !chain_id.chars().all(|c| c.is_ascii_digit()) {
+                return Err(Error::InvalidEip681);
+            }
+            let chain_id = chain_id.parse::<u64>().map_err(|_| Error::InvalidEip681)?;
+            (address, Some(chain_id))
+        }
+        None => (target, None),
+    };
+    let address = normalize_eth_address(address)?;
+    let mut parameters = Vec::new();
+    if let Some(query) = query {
+        for parameter in query.split('&') {
+            match parameter.split_once('=') {
+                Some((key, value)) if !key.is_empty() => {
+                    parameters.push((key.to_string(), value.to_string()))
+                }
+                _ => return Err(Error::InvalidEip681),
+            }
+        }
+    }
+    Ok(Eip681 {
+        address,
+        chain_id,
+        function_name,
+        parameters,
+    })
+}
+
+/// Split `message || signature` blob into message and trailing 65-byte
+/// recoverable `Ecdsa` signature
+pub fn split_eth_signed(blob: &[u8]) -> Result<(&[u8], &[u8; 65])> {
+    if blob.len() < 65 {
+        return Err(Error::SignedMessageTooShort);
+    }
+    let (message, signature) = blob.split_at(blob.len() - 65);
+    let signature = signature
+        .try_into()
+        .expect("split at 65 bytes from the end, static length");
+    Ok((message, signature))
+}
+
+/// Ethereum address
+///
+/// Raw 20 address bytes, parsed from and printed into hexadecimal string
+/// with or without [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct EthAddress(H160);
+
+impl EthAddress {
+    /// Print address with EIP-55 checksum, with `0x` prefix
+    pub fn to_checksummed(&self) -> String {
+        checksum_eth_address(&self.0)
+    }
+
+    /// Print address in lowercase, with `0x` prefix
+    pub fn to_lowercase(&self) -> String {
+        Hex0x::ensure(&hex::encode(self.0.as_bytes())).into_owned()
+    }
+
+    /// Get raw address bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl FromStr for EthAddress {
+    type Err = Error;
+
+    /// Parse address, same as [`normalize_eth_address`]
+    fn from_str(address: &str) -> Result<Self> {
+        Ok(Self(normalize_eth_address(address)?))
+    }
+}
+
+impl From<H160> for EthAddress {
+    fn from(address: H160) -> Self {
+        Self(address)
+    }
+}
+
+impl From<EthAddress> for H160 {
+    fn from(address: EthAddress) -> Self {
+        address.0
+    }
+}
+
+/// [EIP-712](https://eips.ethereum.org/EIPS/eip-712) domain
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eip712Domain {
+    /// Name of the signing domain, i.e. of dApp or protocol
+    pub name: String,
+
+    /// Current major version of the signing domain
+    pub version: String,
+
+    /// [EIP-155](https://eips.ethereum.org/EIPS/eip-155) chain id
+    pub chain_id: u64,
+
+    /// Address of the contract that will verify the signature
+    pub verifying_contract: H160,
+}
+
+/// Calculate [EIP-712](https://eips.ethereum.org/EIPS/eip-712) domain
+/// separator
+///
+/// Domain separator is `keccak256` hash of encoded domain struct, with all
+/// four fields of [`Eip712Domain`].
+pub fn eip712_domain_hash(domain: &Eip712Domain) -> H256 {
+    let type_hash = keccak_256(
+        b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+    );
+    let mut encoded = Vec::with_capacity(5 * 32);
+    encoded.extend_from_slice(&type_hash);
+    encoded.extend_from_slice(&keccak_256(domain.name.as_bytes()));
+    encoded.extend_from_slice(&keccak_256(domain.version.as_bytes()));
+    // `uint256` and `address` values are encoded as 32 bytes, big endian
+    encoded.extend_from_slice(&[0; 24]);
+    encoded.extend_from_slice(&domain.chain_id.to_be_bytes());
+    encoded.extend_from_slice(&[0; 12]);
+    encoded.extend_from_slice(domain.verifying_contract.as_bytes());
+    H256(keccak_256(&encoded))
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+    use crate::helpers::{pack_signature, unhex_fixed};
+    use crate::test_values::alice_ecdsa;
+    use sp_runtime::MultiSigner;
+
+    #[test]
+    fn eth_addr_eq_ct_agrees_with_eth_addr_eq() {
+        let checksummed = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let lowercase = "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed";
+        let other = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+        for (a, b) in [
+            (checksummed, lowercase),
+            (checksummed, other),
+            (lowercase, other),
+            (other, other),
+        ] {
+            assert_eq!(eth_addr_eq_ct(a, b).unwrap(), eth_addr_eq(a, b).unwrap());
+        }
+        assert!(eth_addr_eq_ct(checksummed, lowercase).unwrap());
+        assert!(!eth_addr_eq_ct(checksummed, other).unwrap());
+    }
+
+    #[test]
+    fn eth_address_bad_checksum() {
+        let result = eth_addr_eq_ct(
+            "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        );
+        if let Err(Error::EthereumChecksumMismatch) = result {
+        } else {
+            panic!("Expected checksum error, got {:?}", result);
+        }
+    }
+
+    #[test]
+    fn eth_address_round_trip() {
+        let address = EthAddress::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(
+            address.to_checksummed(),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            address.to_lowercase(),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+        assert_eq!(
+            address.as_bytes(),
+            hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap()
+        );
+        assert_eq!(
+            EthAddress::from_str(&address.to_lowercase()).unwrap(),
+            address
+        );
+        assert!(matches!(
+            EthAddress::from_str("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+            Err(Error::EthereumChecksumMismatch)
+        ));
+    }
+
+    #[test]
+    fn eth_address_for_alice() {
+        let address = eth_address_from_secret_uri(
+            "bottom drive obey lake curtain smoke basket hold race lonely fit walk//Alice",
+        )
+        .unwrap();
+        assert_eq!(
+            checksum_eth_address(&address),
+            "0xE04CC55ebEE1cBCE552f250e85c57B70B2E2625b"
+        );
+        assert!(matches!(
+            eth_address_from_secret_uri("not a seed phrase//Alice"),
+            Err(Error::InvalidSecretUri)
+        ));
+    }
+
+    #[test]
+    fn checksum_eth_addresses_batch() {
+        let addresses: Vec<H160> = [
+            "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "fb6916095ca1df60bb79ce92ce3ea74c37c5d359",
+            "0000000000000000000000000000000000000000",
+        ]
+        .iter()
+        .map(|a| H160::from_slice(&hex::decode(a).unwrap()))
+        .collect();
+        let batch = checksum_eth_addresses(&addresses);
+        assert_eq!(batch.len(), addresses.len());
+        for (address, checksummed) in addresses.iter().zip(batch.iter()) {
+            assert_eq!(&checksum_eth_address(address), checksummed);
+        }
+        assert_eq!(batch[1], "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+    }
+
+    #[test]
+    fn eip712_domain_separator() {
+        // domain of `Mail` example from EIP-712
+        let domain = Eip712Domain {
+            name: String::from("Ether Mail"),
+            version: String::from("1"),
+            chain_id: 1,
+            verifying_contract: normalize_eth_address("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC")
+                .unwrap(),
+        };
+        assert_eq!(
+            hex::encode(eip712_domain_hash(&domain)),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+    }
+
+    #[test]
+    fn caip10_account() {
+        let (chain_id, address) =
+            parse_caip10("eip155:1:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(chain_id, 1);
+        assert_eq!(
+            checksum_eth_address(&address),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert!(matches!(
+            parse_caip10("cosmos:1:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(Error::UnsupportedCaip10Namespace(namespace)) if namespace == "cosmos"
+        ));
+        for malformed in [
+            "eip155:0x1:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "eip155::0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "eip155:+1:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "eip155:1",
+        ] {
+            assert!(matches!(parse_caip10(malformed), Err(Error::InvalidCaip10)));
+        }
+    }
+
+    #[test]
+    fn eth_address_for_alice_secret() {
+        let mut secret: [u8; 32] =
+            hex::decode("cb6df9de1efca7a3998a8ead4e02159d5fa99c3e0d4fd6432667390bb4726854")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let address = eth_address_from_secret(&mut secret).unwrap();
+        assert_eq!(secret, [0; 32]);
+        assert_eq!(
+            checksum_eth_address(&address),
+            "0xE04CC55ebEE1cBCE552f250e85c57B70B2E2625b"
+        );
+
+        // secret above the curve order is not valid, buffer is wiped anyway
+        let mut invalid = [0xff; 32];
+        assert!(matches!(
+            eth_address_from_secret(&mut invalid),
+            Err(Error::KeyDerivation)
+        ));
+        assert_eq!(invalid, [0; 32]);
+    }
+
+    #[test]
+    fn shortened_eth_address() {
+        let address = normalize_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        let full = checksum_eth_address(&address);
+        let short = shorten_eth_address(&address, 4, 5);
+        assert_eq!(short, "0x5aAe...BeAed");
+        assert_eq!(&short[..6], &full[..6]);
+        assert_eq!(&short[short.len() - 5..], &full[full.len() - 5..]);
+        assert_eq!(shorten_eth_address(&address, 20, 20), full);
+    }
+
+    #[test]
+    fn eip55_into_buffer() {
+        let address =
+            H160::from_slice(&hex::decode("fb6916095ca1df60bb79ce92ce3ea74c37c5d359").unwrap());
+        let mut out = [0; 42];
+        eip55_into(&address, &mut out);
+        assert_eq!(&out[..], checksum_eth_address(&address).as_bytes());
+        assert_eq!(&out[..], b"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359");
+    }
+
+    #[test]
+    fn eth_addresses_for_publics() {
+        let publics: Vec<ecdsa::Public> = [
+            "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1",
+            "03e5f070dc936e69435f278cb5ae7226bc9088093be3c398948da3214e82ff1f0c",
+        ]
+        .iter()
+        .map(|public| ecdsa::Public::from_raw(hex::decode(public).unwrap().try_into().unwrap()))
+        .collect();
+        let batch = eth_addresses_batch(&publics).unwrap();
+        assert_eq!(batch.len(), publics.len());
+        for (public, address) in publics.iter().zip(batch.iter()) {
+            assert_eq!(&ecdsa_public_to_eth_address(public).unwrap(), address);
+        }
+
+        let off_curve = ecdsa::Public::from_raw(
+            hex::decode("020000000000000000000000000000000000000000000000000000000000000005")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+        assert!(matches!(
+            eth_addresses_batch(&[publics[0].clone(), off_curve]),
+            Err(Error::PublicKeyNotOnCurve)
+        ));
+    }
+
+    #[test]
+    fn split_signed_message() {
+        let signature = pack_signature(&[1; 32], &[2; 32], 27);
+        let blob = [b"hello".as_slice(), &signature].concat();
+        let (message, split_signature) = split_eth_signed(&blob).unwrap();
+        assert_eq!(message, b"hello");
+        assert_eq!(split_signature, &signature);
+
+        assert!(matches!(
+            split_eth_signed(&signature[..64]),
+            Err(Error::SignedMessageTooShort)
+        ));
+    }
+
+    #[test]
+    fn eip681_payment_uri() {
+        let address = normalize_eth_address("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+        assert_eq!(
+            parse_eip681("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap(),
+            Eip681 {
+                address,
+                chain_id: None,
+                function_name: None,
+                parameters: Vec::new(),
+            }
+        );
+        assert_eq!(
+            parse_eip681(
+                "ethereum:pay-0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed@1?value=2.014e18"
+            )
+            .unwrap(),
+            Eip681 {
+                address,
+                chain_id: Some(1),
+                function_name: None,
+                parameters: vec![(String::from("value"), String::from("2.014e18"))],
+            }
+        );
+        assert!(matches!(
+            parse_eip681("bitcoin:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Err(Error::InvalidEip681)
+        ));
+        assert!(matches!(
+            parse_eip681("ethereum:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed@?value=1"),
+            Err(Error::InvalidEip681)
+        ));
+    }
+
+    #[test]
+    fn contract_create_address() {
+        let deployer = normalize_eth_address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0").unwrap();
+        for (nonce, expected) in [
+            (0, "cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"),
+            (1, "343c43a37d37dff08ae8c4a11544c718abb4fcf8"),
+            (2, "f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"),
+            (3, "fffd933a0bc612844eaf0c6fe3e5b8e9b6c1d19c"),
+            (0x80, "08e190dcb7b73f5fcdabb43e102215c83659a76d"),
+            (u64::MAX, "9bc924993b60399df164c3763a964301d3db95ca"),
+        ] {
+            assert_eq!(
+                compute_create_address(&deployer, nonce),
+                normalize_eth_address(expected).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn contract_create2_address() {
+        // examples from EIP-1014, init code is `0x00`
+        let init_code_hash = H256(keccak_256(&[0]));
+        let salt =
+            unhex_fixed::<32>("000000000000000000000000feed000000000000000000000000000000000000")
+                .unwrap();
+        for (deployer, salt, expected) in [
+            (
+                "0x0000000000000000000000000000000000000000",
+                [0; 32],
+                "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                [0; 32],
+                "0xB928f69Bb1D91Cd65274e3c79d8986362984fDA3",
+            ),
+            (
+                "0xdeadbeef00000000000000000000000000000000",
+                salt,
+                "0xD04116cDd17beBE565EB2422F2497E06cC1C9833",
+            ),
+        ] {
+            let deployer = normalize_eth_address(deployer).unwrap();
+            assert_eq!(
+                checksum_eth_address(&compute_create2_address(&deployer, &salt, &init_code_hash)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn eth_address_storage_format() {
+        let alice = match alice_ecdsa() {
+            MultiSigner::Ecdsa(public) => public,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            eth_address_legacy_string(&alice).unwrap(),
+            "e04cc55ebee1cbce552f250e85c57b70b2e2625b"
+        );
+    }
+
+    #[test]
+    fn eip55_validity() {
+        assert!(is_valid_eip55("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        assert!(!is_valid_eip55(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+        ));
+        assert!(!is_valid_eip55(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        ));
+        assert!(!is_valid_eip55("5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        assert!(!is_valid_eip55("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA"));
+        assert!(!is_valid_eip55(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAzz"
+        ));
+    }
+
+    #[test]
+    fn eth_address_bytes_display() {
+        let bytes: [u8; 20] = hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let address = eth_address_from_bytes(&bytes);
+        assert_eq!(address.as_bytes(), bytes);
+        assert_eq!(
+            eth_address_display(&address, true),
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+        );
+        assert_eq!(
+            eth_address_display(&address, false),
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        );
+    }
+}
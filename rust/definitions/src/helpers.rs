@@ -1,20 +1,33 @@
 //! Common helper functions
 
 use hex;
-use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
+use parity_scale_codec::Encode;
+use sp_core::crypto::{AccountId32, DeriveJunction, Ss58AddressFormat, Ss58Codec, JUNCTION_ID_LEN};
+use sp_core::{blake2_256, ecdsa, ed25519, sr25519, H256};
 #[cfg(feature = "signer")]
-use sp_core::{crypto::AccountId32, ecdsa, ed25519, sr25519};
-use sp_runtime::MultiSigner;
+use sp_core::{Pair, H160};
+use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+use std::borrow::Cow;
 #[cfg(feature = "signer")]
 use std::convert::TryInto;
+#[cfg(feature = "signer")]
+use zeroize::Zeroize;
 
 #[cfg(feature = "signer")]
 use plot_icon::{generate_png, EMPTY_PNG};
 
 use crate::crypto::Encryption;
+use crate::error::{Error, Result};
+#[cfg(feature = "signer")]
+use crate::ethereum::{checksum_eth_address, ecdsa_public_to_eth_address};
+#[cfg(feature = "signer")]
+use crate::identicon::{identicon_seed, make_identicon_sized};
+use crate::reader::ByteReader;
 #[cfg(feature = "signer")]
-use crate::error::Error;
-use crate::error::Result;
+use crate::redacted::Redacted;
+#[cfg(any(feature = "fuzzing", feature = "serde"))]
+use crate::ss58::decode_ss58;
+use crate::ss58::same_account;
 
 /// Decode hexadecimal `&str` into `Vec<u8>`, with descriptive error  
 ///
@@ -24,14 +37,48 @@ use crate::error::Result;
 /// error with details on what exactly turned out to be invalid hexadecimal
 /// string.  
 pub fn unhex(hex_entry: &str) -> Result<Vec<u8>> {
-    let hex_entry = {
-        if let Some(a) = hex_entry.strip_prefix("0x") {
-            a
+    Ok(hex::decode(Hex0x::strip(hex_entry))?)
+}
+
+/// Decode hexadecimal `&str` into fixed-size array of `N` bytes
+///
+/// `0x` prefix is optional, same as in [`unhex`].
+pub fn unhex_fixed<const N: usize>(hex_entry: &str) -> Result<[u8; N]> {
+    let body = Hex0x::strip(hex_entry);
+    if body.len() != 2 * N {
+        hex::decode(body)?;
+        return Err(Error::WrongHashLength {
+            expected: N,
+            got: body.len() / 2,
+        });
+    }
+    let mut out = [0; N];
+    hex::decode_to_slice(body, &mut out)?;
+    Ok(out)
+}
+
+/// `0x` prefix policy for hexadecimal strings
+///
+/// Hexadecimal input is accepted with or without `0x` prefix. Base58 addresses
+/// never have it, Ethereum addresses are always printed with it.
+pub struct Hex0x;
+
+impl Hex0x {
+    pub const PREFIX: &'static str = "0x";
+
+    /// Remove single `0x` prefix, if there is one
+    pub fn strip(hex_entry: &str) -> &str {
+        hex_entry.strip_prefix(Self::PREFIX).unwrap_or(hex_entry)
+    }
+
+    /// Add `0x` prefix, if there is none
+    pub fn ensure(hex_entry: &str) -> Cow<'_, str> {
+        if hex_entry.starts_with(Self::PREFIX) {
+            Cow::Borrowed(hex_entry)
         } else {
-            hex_entry
+            Cow::Owned(format!("{}{}", Self::PREFIX, hex_entry))
         }
-    };
-    Ok(hex::decode(hex_entry)?)
+    }
 }
 
 /// Get `Vec<u8>` public key from
@@ -44,6 +91,119 @@ pub fn multisigner_to_public(m: &MultiSigner) -> Vec<u8> {
     }
 }
 
+/// Print public key from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// as lowercase hexadecimal string, with `0x` prefix if `prefixed` is `true`
+pub fn public_key_hex(m: &MultiSigner, prefixed: bool) -> String {
+    let public_hex = hex::encode(multisigner_to_public(m));
+    if prefixed {
+        Hex0x::ensure(&public_hex).into_owned()
+    } else {
+        public_hex
+    }
+}
+
+/// Print [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// as encryption name and `0x`-prefixed lowercase hexadecimal public key,
+/// e.g. `sr25519:0xd435...a27d`, for logs and snapshot tests
+pub fn describe_multisigner(m: &MultiSigner) -> String {
+    format!(
+        "{}:{}",
+        multisigner_to_encryption(m).show(),
+        public_key_hex(m, true)
+    )
+}
+
+/// Get decimal verification code of `digits` digits for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html),
+/// e.g. to read aloud for out-of-band account verification
+///
+/// Digits are taken from `blake2_256` hashes of the raw public key followed
+/// by little endian `u32` counter, one digit per hash byte. Bytes `250..=255`
+/// are skipped, so that all digits are equally probable.
+pub fn verification_code(m: &MultiSigner, digits: usize) -> String {
+    let public = multisigner_to_public(m);
+    let mut code = String::with_capacity(digits);
+    let mut counter: u32 = 0;
+    while code.len() < digits {
+        let hash = blake2_256(&[public.as_slice(), &counter.to_le_bytes()].concat());
+        for byte in hash.iter().filter(|byte| **byte < 250) {
+            if code.len() == digits {
+                break;
+            }
+            code.push(char::from(b'0' + byte % 10));
+        }
+        counter += 1;
+    }
+    code
+}
+
+/// Calculate digest of signing request, for audit logs
+///
+/// Digest is `blake2_256` hash of [`describe_multisigner`] output for the
+/// signer followed by the payload.
+pub fn signing_request_digest(signer: &MultiSigner, payload: &[u8]) -> [u8; 32] {
+    blake2_256(&[describe_multisigner(signer).as_bytes(), payload].concat())
+}
+
+/// Get sort key for account, for stable ordering of accounts in lists
+///
+/// Key is SCALE-encoded [`Encryption`](crate::crypto::Encryption), a single
+/// byte, followed by raw public key, and does not depend on base58 prefix.
+pub fn account_sort_key(m: &MultiSigner, encryption: &Encryption) -> Vec<u8> {
+    let mut key = encryption.encode();
+    key.extend_from_slice(&multisigner_to_public(m));
+    key
+}
+
+/// Get short fingerprint of
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html),
+/// for logs
+///
+/// Fingerprint is first 4 bytes of `blake2_256` hash of the raw public key,
+/// as lowercase hexadecimal string, and does not depend on base58 prefix.
+pub fn account_fingerprint(m: &MultiSigner) -> String {
+    hex::encode(&blake2_256(&multisigner_to_public(m))[..4])
+}
+
+/// Well-known accounts, by raw public key
+///
+/// Pallet accounts are `modl` followed by the pallet id, padded with zeroes.
+const WELL_KNOWN_ACCOUNTS: [([u8; 32], &str); 2] = [
+    ([0; 32], "Zero account"),
+    (
+        *b"modlpy/trsry\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0",
+        "Treasury",
+    ),
+];
+
+/// Get the name of well-known account, if
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// is one
+///
+/// Accounts are compared by raw public key, for `Ed25519` and `Sr25519` only.
+pub fn well_known_account(m: &MultiSigner) -> Option<&'static str> {
+    let public = match m {
+        MultiSigner::Ed25519(a) => a.0,
+        MultiSigner::Sr25519(a) => a.0,
+        MultiSigner::Ecdsa(_) => return None,
+    };
+    WELL_KNOWN_ACCOUNTS
+        .iter()
+        .find(|(account, _)| account == &public)
+        .map(|(_, name)| *name)
+}
+
+/// Calculate 128-bit `twox` hash, as used in substrate storage keys
+pub fn twox_128(data: &[u8]) -> [u8; 16] {
+    sp_core::twox_128(data)
+}
+
+/// Calculate 256-bit `twox` hash
+pub fn twox_256(data: &[u8]) -> [u8; 32] {
+    sp_core::twox_256(data)
+}
+
 /// Get [`Encryption`](crate::crypto::Encryption) from
 /// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)  
 pub fn multisigner_to_encryption(m: &MultiSigner) -> Encryption {
@@ -54,11 +214,21 @@ pub fn multisigner_to_encryption(m: &MultiSigner) -> Encryption {
     }
 }
 
+/// Get [`Encryption`](crate::crypto::Encryption) from
+/// [`MultiSignature`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSignature.html)  
+pub fn multisignature_to_encryption(sig: &MultiSignature) -> Encryption {
+    match sig {
+        MultiSignature::Ed25519(_) => Encryption::Ed25519,
+        MultiSignature::Sr25519(_) => Encryption::Sr25519,
+        MultiSignature::Ecdsa(_) => Encryption::Ecdsa,
+    }
+}
+
 /// Print identicon from
 /// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)  
 #[cfg(feature = "signer")]
 pub fn make_identicon_from_multisigner(multisigner: &MultiSigner) -> Vec<u8> {
-    make_identicon(&multisigner_to_public(multisigner))
+    make_identicon(&identicon_seed(multisigner))
 }
 
 #[cfg(feature = "signer")]
@@ -67,15 +237,41 @@ pub fn make_identicon_from_account(account: AccountId32) -> Vec<u8> {
 }
 
 #[cfg(feature = "signer")]
-fn make_identicon(into_id: &[u8]) -> Vec<u8> {
+pub(crate) fn make_identicon(into_id: &[u8]) -> Vec<u8> {
     match generate_png(into_id, 72) {
         Ok(a) => a,
         Err(_) => EMPTY_PNG.to_vec(),
     }
 }
 
+/// Check that public key length matches the [`Encryption`] and get a copy
+/// of the public key
+///
+/// Could be used to validate the public key prior to making
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html).
+pub fn public_from_bytes_checked(public: &[u8], encryption: &Encryption) -> Result<Vec<u8>> {
+    if public.len() == encryption.public_key_len() {
+        Ok(public.to_vec())
+    } else {
+        Err(Error::WrongPublicKeyLength)
+    }
+}
+
+/// Check that compressed `Ecdsa` public key starts with `0x02` or `0x03`
+///
+/// Cheap check for corrupted keys, the key is not parsed as a curve point.
+pub fn validate_compressed_prefix(public: &[u8; 33]) -> Result<()> {
+    match public[0] {
+        0x02 | 0x03 => Ok(()),
+        a => Err(Error::InvalidPublicKeyPoint(a)),
+    }
+}
+
 /// Get [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
 /// from public key and [`Encryption`](crate::crypto::Encryption)
+///
+/// `Ecdsa` public key must have valid compressed key prefix, see
+/// [`validate_compressed_prefix`].
 #[cfg(feature = "signer")]
 pub fn get_multisigner(public: &[u8], encryption: &Encryption) -> Result<MultiSigner> {
     match encryption {
@@ -98,11 +294,346 @@ pub fn get_multisigner(public: &[u8], encryption: &Encryption) -> Result<MultiSi
                 .to_vec()
                 .try_into()
                 .map_err(|_| Error::WrongPublicKeyLength)?;
+            validate_compressed_prefix(&into_pubkey)?;
             Ok(MultiSigner::Ecdsa(ecdsa::Public::from_raw(into_pubkey)))
         }
     }
 }
 
+/// Builder for [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// with configurable public key validation
+///
+/// By default only the public key length and `Ecdsa` compressed key prefix
+/// are checked, same as in [`get_multisigner`]. With `validate_point(true)`
+/// the `Ecdsa` public key is additionally checked to be a valid point on
+/// secp256k1 curve, this is more expensive. `Ed25519` and `Sr25519` public
+/// keys are not point-validated.
+#[cfg(feature = "signer")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MultisignerBuilder {
+    validate_point: bool,
+}
+
+#[cfg(feature = "signer")]
+impl MultisignerBuilder {
+    /// New builder, with length-only validation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the `Ecdsa` public key must be a valid curve point
+    pub fn validate_point(mut self, validate_point: bool) -> Self {
+        self.validate_point = validate_point;
+        self
+    }
+
+    /// Get `MultiSigner` from public key and
+    /// [`Encryption`](crate::crypto::Encryption)
+    pub fn build(&self, public: &[u8], encryption: &Encryption) -> Result<MultiSigner> {
+        let multisigner = get_multisigner(public, encryption)?;
+        if self.validate_point {
+            if let MultiSigner::Ecdsa(ref pubkey) = multisigner {
+                libsecp256k1::PublicKey::parse_compressed(&pubkey.0)
+                    .map_err(|_| Error::PublicKeyNotOnCurve)?;
+            }
+        }
+        Ok(multisigner)
+    }
+}
+
+/// Public key together with its [`Encryption`](crate::crypto::Encryption)
+///
+/// Public key length is checked to match the encryption on construction, and
+/// `Ecdsa` public key is checked to have valid compressed key prefix, see
+/// [`validate_compressed_prefix`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaggedPublic {
+    encryption: Encryption,
+    bytes: Vec<u8>,
+}
+
+impl TaggedPublic {
+    /// New `TaggedPublic`, with public key length and `Ecdsa` prefix checks
+    pub fn new(encryption: Encryption, bytes: &[u8]) -> Result<Self> {
+        let bytes = public_from_bytes_checked(bytes, &encryption)?;
+        if let Encryption::Ecdsa = encryption {
+            let mut public = [0; 33];
+            public.copy_from_slice(&bytes);
+            validate_compressed_prefix(&public)?;
+        }
+        Ok(Self { encryption, bytes })
+    }
+
+    pub fn encryption(&self) -> &Encryption {
+        &self.encryption
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Get [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+    #[cfg(feature = "signer")]
+    pub fn to_multisigner(&self) -> MultiSigner {
+        get_multisigner(&self.bytes, &self.encryption)
+            .expect("public key length and prefix are checked on construction")
+    }
+
+    /// Print public key in base58 format, see [`print_multisigner_as_base58`]
+    #[cfg(feature = "signer")]
+    pub fn to_base58(&self, optional_prefix: Option<u16>) -> String {
+        print_multisigner_as_base58(&self.to_multisigner(), optional_prefix)
+    }
+
+    /// Get Ethereum address, for `Ecdsa` public keys only
+    #[cfg(feature = "signer")]
+    pub fn to_eth_address(&self) -> Result<H160> {
+        match self.to_multisigner() {
+            MultiSigner::Ecdsa(public) => ecdsa_public_to_eth_address(&public),
+            MultiSigner::Ed25519(_) | MultiSigner::Sr25519(_) => Err(Error::NotSecp256k1Key),
+        }
+    }
+}
+
+/// Check if [`Encryption`](crate::crypto::Encryption) is the one of
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// variant
+///
+/// Could be used to validate decoded `MultiSigner` and `Encryption` pairs.
+pub fn encryption_valid_for(m: &MultiSigner, encryption: &Encryption) -> bool {
+    matches!(
+        (m, encryption),
+        (MultiSigner::Ed25519(_), Encryption::Ed25519)
+            | (MultiSigner::Sr25519(_), Encryption::Sr25519)
+            | (MultiSigner::Ecdsa(_), Encryption::Ecdsa)
+    )
+}
+
+/// Account imported from polkadot.js account export
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportedAccount {
+    /// Base58 address, as in the export
+    pub address: String,
+
+    /// Account name, if any
+    pub name: Option<String>,
+
+    /// Public key with encryption
+    pub public: TaggedPublic,
+}
+
+/// Parse polkadot.js account export JSON
+///
+/// Encryption is taken from `encoding.content`, public key is decoded from
+/// base58 `address`. Encrypted secret in `encoded` is not used.
+#[cfg(feature = "serde")]
+pub fn parse_polkadotjs_account(json: &str) -> Result<ImportedAccount> {
+    let export: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| Error::InvalidPolkadotJsExport(e.to_string()))?;
+    let address = export["address"]
+        .as_str()
+        .ok_or_else(|| Error::InvalidPolkadotJsExport(String::from("no address")))?;
+    let scheme = export["encoding"]["content"]
+        .as_array()
+        .and_then(|content| {
+            content
+                .iter()
+                .filter_map(|a| a.as_str())
+                .find(|a| *a != "pkcs8")
+        })
+        .ok_or_else(|| Error::InvalidPolkadotJsExport(String::from("no encoding content")))?;
+    let encryption = match scheme {
+        "ed25519" => Encryption::Ed25519,
+        "sr25519" => Encryption::Sr25519,
+        "ecdsa" => Encryption::Ecdsa,
+        a => return Err(Error::UnsupportedPolkadotJsEncoding(a.to_string())),
+    };
+    let (_, public) = decode_ss58(address)?;
+    Ok(ImportedAccount {
+        address: address.to_string(),
+        name: export["meta"]["name"].as_str().map(String::from),
+        public: TaggedPublic::new(encryption, &public)?,
+    })
+}
+
+/// SCALE-encode verifier record, i.e.
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// of the verifier together with [`Encryption`](crate::crypto::Encryption)
+pub fn encode_verifier(m: &MultiSigner, encryption: &Encryption) -> Vec<u8> {
+    (m, encryption).encode()
+}
+
+/// Decode verifier record produced by [`encode_verifier`]
+///
+/// All input bytes must be used in decoding. Encryption must match the
+/// `MultiSigner` variant.
+pub fn decode_verifier(bytes: &[u8]) -> Result<(MultiSigner, Encryption)> {
+    let mut reader = ByteReader::new(bytes);
+    let m = match encryption_from_index(reader.read_bytes(1)?[0])? {
+        Encryption::Ed25519 => {
+            MultiSigner::Ed25519(ed25519::Public::from_raw(reader.read_array()?))
+        }
+        Encryption::Sr25519 => {
+            MultiSigner::Sr25519(sr25519::Public::from_raw(reader.read_array()?))
+        }
+        Encryption::Ecdsa => MultiSigner::Ecdsa(ecdsa::Public::from_raw(reader.read_array()?)),
+    };
+    let encryption = encryption_from_index(reader.read_bytes(1)?[0])?;
+    reader.expect_end()?;
+    if !encryption_valid_for(&m, &encryption) {
+        return Err(Error::VerifierEncryptionMismatch);
+    }
+    Ok((m, encryption))
+}
+
+/// Calculate integrity hash of address entry
+///
+/// Hash is `blake2_256` of SCALE-encoded public key, encryption, network
+/// genesis hash and derivation path, in this order.
+pub fn address_entry_hash(
+    m: &MultiSigner,
+    encryption: &Encryption,
+    genesis_hash: &H256,
+    path: &str,
+) -> [u8; 32] {
+    blake2_256(&(m, encryption, genesis_hash, path).encode())
+}
+
+/// Check address entry against integrity hash made with
+/// [`address_entry_hash`]
+pub fn verify_address_entry_hash(
+    m: &MultiSigner,
+    encryption: &Encryption,
+    genesis_hash: &H256,
+    path: &str,
+    hash: &[u8; 32],
+) -> bool {
+    &address_entry_hash(m, encryption, genesis_hash, path) == hash
+}
+
+/// Get [`Encryption`](crate::crypto::Encryption) of verifier record produced
+/// by [`encode_verifier`], without decoding the whole record
+///
+/// Encryption is determined by the leading `MultiSigner` variant tag. Rest
+/// of the record is not checked, use [`decode_verifier`] for that.
+pub fn peek_verifier_encryption(bytes: &[u8]) -> Result<Encryption> {
+    encryption_from_index(ByteReader::new(bytes).read_bytes(1)?[0])
+}
+
+/// Get [`Encryption`](crate::crypto::Encryption) from SCALE variant index,
+/// used both for `MultiSigner` and for `Encryption` in verifier records
+fn encryption_from_index(index: u8) -> Result<Encryption> {
+    match index {
+        0 => Ok(Encryption::Ed25519),
+        1 => Ok(Encryption::Sr25519),
+        2 => Ok(Encryption::Ecdsa),
+        a => Err(Error::UnknownVerifierEncryption(a)),
+    }
+}
+
+/// Verify `Sr25519` signature made with given signing context
+///
+/// Substrate signs with `substrate` signing context, this function is for the
+/// payloads signed with other contexts.
+#[cfg(feature = "signer")]
+pub fn verify_sr25519_with_context(
+    public: &sr25519::Public,
+    context: &[u8],
+    message: &[u8],
+    signature: &[u8; 64],
+) -> bool {
+    let public = match schnorrkel::PublicKey::from_bytes(&public.0) {
+        Ok(a) => a,
+        Err(_) => return false,
+    };
+    let signature = match schnorrkel::Signature::from_bytes(signature) {
+        Ok(a) => a,
+        Err(_) => return false,
+    };
+    public.verify_simple(context, message, &signature).is_ok()
+}
+
+/// Opening tag of wrapped message, see [`wrap_bytes_for_signing`]
+pub const WRAP_BYTES_PREFIX: &[u8] = b"<Bytes>";
+
+/// Closing tag of wrapped message, see [`wrap_bytes_for_signing`]
+pub const WRAP_BYTES_POSTFIX: &[u8] = b"</Bytes>";
+
+/// Wrap arbitrary message into `<Bytes>` tags before signing, same as
+/// polkadot.js `signRaw` does
+pub fn wrap_bytes_for_signing(message: &[u8]) -> Vec<u8> {
+    [WRAP_BYTES_PREFIX, message, WRAP_BYTES_POSTFIX].concat()
+}
+
+/// Get message wrapped with [`wrap_bytes_for_signing`], if the input is
+/// wrapped
+pub fn unwrap_bytes(wrapped: &[u8]) -> Option<&[u8]> {
+    wrapped
+        .strip_prefix(WRAP_BYTES_PREFIX)?
+        .strip_suffix(WRAP_BYTES_POSTFIX)
+}
+
+/// Largest transaction signing payload that is signed as is
+///
+/// Longer payloads are hashed with `blake2_256` before signing.
+pub const MAX_UNHASHED_PAYLOAD_LEN: usize = 256;
+
+/// Verify transaction signature made by `signer` over `payload`
+///
+/// With `wrap_long` the payloads longer than [`MAX_UNHASHED_PAYLOAD_LEN`]
+/// are expected to be signed as `blake2_256` hash, same as substrate does for
+/// transaction signing payloads.
+#[cfg(feature = "signer")]
+pub fn verify_tx_signature(
+    signer: &MultiSigner,
+    payload: &[u8],
+    signature: &[u8],
+    wrap_long: bool,
+) -> Result<bool> {
+    if signature.len() != multisigner_to_encryption(signer).signature_len() {
+        return Err(Error::WrongSignatureLength);
+    }
+    let hashed;
+    let message = if wrap_long && payload.len() > MAX_UNHASHED_PAYLOAD_LEN {
+        hashed = blake2_256(payload);
+        &hashed[..]
+    } else {
+        payload
+    };
+    Ok(match signer {
+        MultiSigner::Ed25519(public) => {
+            let signature = ed25519::Signature::from_raw(
+                signature.try_into().expect("signature length is checked"),
+            );
+            ed25519::Pair::verify(&signature, message, public)
+        }
+        MultiSigner::Sr25519(public) => {
+            let signature = sr25519::Signature::from_raw(
+                signature.try_into().expect("signature length is checked"),
+            );
+            sr25519::Pair::verify(&signature, message, public)
+        }
+        MultiSigner::Ecdsa(public) => {
+            let signature = ecdsa::Signature::from_raw(
+                signature.try_into().expect("signature length is checked"),
+            );
+            ecdsa::Pair::verify(&signature, message, public)
+        }
+    })
+}
+
+/// Verify signature made by `signer` over message wrapped with
+/// [`wrap_bytes_for_signing`], as dapps sign arbitrary messages
+#[cfg(feature = "signer")]
+pub fn verify_wrapped_message(
+    signer: &MultiSigner,
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool> {
+    verify_tx_signature(signer, &wrap_bytes_for_signing(message), signature, false)
+}
+
 /// Print [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
 /// in base58 format
 ///
@@ -136,6 +667,374 @@ pub fn print_multisigner_as_base58(
     }
 }
 
+/// Address book entry after [`merge_with_seed_accounts`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MergedEntry {
+    /// Base58 address
+    pub address: String,
+
+    /// Derivation path, for seed-derived entries
+    pub path: Option<String>,
+
+    /// Entry is derived from the seed and could be used for signing, as
+    /// opposed to watch-only imported entry
+    pub signable: bool,
+}
+
+/// Merge imported base58 addresses with `(path, address)` pairs of accounts
+/// derived from the seed
+///
+/// Accounts are compared with [`same_account`], so the prefixes do not
+/// matter. All seed-derived accounts are kept as signable entries, in their
+/// order. Imported addresses follow as watch-only entries, in their order,
+/// except the ones that are the same account as one of the entries already
+/// added. Imported addresses that could not be decoded are kept.
+pub fn merge_with_seed_accounts(
+    imported: &[String],
+    seed_derived: &[(String, String)],
+) -> Vec<MergedEntry> {
+    let mut merged: Vec<MergedEntry> = seed_derived
+        .iter()
+        .map(|(path, address)| MergedEntry {
+            address: address.to_owned(),
+            path: Some(path.to_owned()),
+            signable: true,
+        })
+        .collect();
+    for address in imported {
+        let is_duplicate = merged
+            .iter()
+            .any(|entry| same_account(&entry.address, address).unwrap_or(false));
+        if !is_duplicate {
+            merged.push(MergedEntry {
+                address: address.to_owned(),
+                path: None,
+                signable: false,
+            });
+        }
+    }
+    merged
+}
+
+/// Maximum length of SCALE-encoded junction name that is used in derivation
+/// verbatim
+///
+/// Longer junction names are replaced with their `blake2_256` hash, same as in
+/// substrate. The limit is not configurable, as any other limit would result
+/// in keys different from the ones derived by substrate for the same path.
+pub const MAX_JUNCTION_LEN: usize = JUNCTION_ID_LEN;
+
+/// Parsed derivation path junction
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Junction {
+    /// Junction as used in derivation
+    pub derive_junction: DeriveJunction,
+
+    /// SCALE-encoded junction name is longer than [`MAX_JUNCTION_LEN`], and
+    /// the junction is made from its hash
+    pub hashed: bool,
+}
+
+/// Parse derivation path into a set of [`Junction`]s
+///
+/// Path is a sequence of soft (`/name`) and hard (`//name`) junctions, empty
+/// path is allowed. Numeric junction names are interpreted as `u64` integers,
+/// same as in substrate secret URI.
+pub fn parse_derivation_path(path: &str) -> Result<Vec<Junction>> {
+    let mut junctions = Vec::new();
+    let mut remaining = path;
+    while !remaining.is_empty() {
+        let (hard, name_and_rest) = match remaining.strip_prefix("//") {
+            Some(a) => (true, a),
+            None => match remaining.strip_prefix('/') {
+                Some(a) => (false, a),
+                None => return Err(Error::InvalidDerivationPath),
+            },
+        };
+        let (name, rest) = match name_and_rest.find('/') {
+            Some(i) => name_and_rest.split_at(i),
+            None => (name_and_rest, ""),
+        };
+        if name.is_empty() {
+            return Err(Error::InvalidDerivationPath);
+        }
+        let (derive_junction, hashed) = match name.parse::<u64>() {
+            Ok(number) => (DeriveJunction::soft(number), false),
+            Err(_) => (
+                DeriveJunction::soft(name),
+                name.encode().len() > MAX_JUNCTION_LEN,
+            ),
+        };
+        junctions.push(Junction {
+            derive_junction: if hard {
+                derive_junction.harden()
+            } else {
+                derive_junction
+            },
+            hashed,
+        });
+        remaining = rest;
+    }
+    Ok(junctions)
+}
+
+/// Check if derivation path has only soft junctions, and so allows public
+/// derivation
+///
+/// Empty path is soft-only.
+pub fn is_soft_only(path: &str) -> Result<bool> {
+    Ok(parse_derivation_path(path)?
+        .iter()
+        .all(|junction| junction.derive_junction.is_soft()))
+}
+
+/// Derive public key from the seed along the derivation path
+///
+/// Derived seed, if any, is zeroized. Key pairs are dropped before the
+/// function returns.
+#[cfg(feature = "signer")]
+fn derive_public<P: Pair>(seed: &[u8], junctions: &[Junction]) -> Result<P::Public> {
+    let pair = P::from_seed_slice(seed).map_err(|_| Error::KeyDerivation)?;
+    let (derived_pair, derived_seed) = pair
+        .derive(junctions.iter().map(|j| j.derive_junction), None)
+        .map_err(|_| Error::KeyDerivation)?;
+    if let Some(mut derived_seed) = derived_seed {
+        derived_seed.as_mut().zeroize();
+    }
+    Ok(derived_pair.public())
+}
+
+/// Key pair of any supported [`Encryption`](crate::crypto::Encryption)
+#[cfg(feature = "signer")]
+pub enum AnyPair {
+    Ed25519(ed25519::Pair),
+    Sr25519(sr25519::Pair),
+    Ecdsa(ecdsa::Pair),
+}
+
+#[cfg(feature = "signer")]
+impl AnyPair {
+    /// Make key pair from raw secret seed
+    ///
+    /// For seed phrases the seed is the mini secret.
+    pub fn from_seed(encryption: &Encryption, seed: &[u8]) -> Result<Self> {
+        match encryption {
+            Encryption::Ed25519 => Ok(AnyPair::Ed25519(
+                ed25519::Pair::from_seed_slice(seed).map_err(|_| Error::KeyDerivation)?,
+            )),
+            Encryption::Sr25519 => Ok(AnyPair::Sr25519(
+                sr25519::Pair::from_seed_slice(seed).map_err(|_| Error::KeyDerivation)?,
+            )),
+            Encryption::Ecdsa => Ok(AnyPair::Ecdsa(
+                ecdsa::Pair::from_seed_slice(seed).map_err(|_| Error::KeyDerivation)?,
+            )),
+        }
+    }
+
+    /// Get [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+    pub fn public(&self) -> MultiSigner {
+        match self {
+            AnyPair::Ed25519(pair) => MultiSigner::Ed25519(pair.public()),
+            AnyPair::Sr25519(pair) => MultiSigner::Sr25519(pair.public()),
+            AnyPair::Ecdsa(pair) => MultiSigner::Ecdsa(pair.public()),
+        }
+    }
+
+    /// Sign message
+    pub fn sign(&self, message: &[u8]) -> MultiSignature {
+        match self {
+            AnyPair::Ed25519(pair) => MultiSignature::Ed25519(pair.sign(message)),
+            AnyPair::Sr25519(pair) => MultiSignature::Sr25519(pair.sign(message)),
+            AnyPair::Ecdsa(pair) => MultiSignature::Ecdsa(pair.sign(message)),
+        }
+    }
+
+    pub fn encryption(&self) -> Encryption {
+        multisigner_to_encryption(&self.public())
+    }
+}
+
+/// Check that the seed derived along the derivation path produces expected
+/// base58 address
+///
+/// Used to verify backups. Seed is the raw secret seed, for seed phrases it is
+/// the mini secret. Base58 prefix of `expected_ss58` is not checked, see
+/// [`same_account`].
+#[cfg(feature = "signer")]
+pub fn verify_seed_matches_address(
+    seed: &[u8],
+    encryption: &Encryption,
+    path: &str,
+    expected_ss58: &str,
+) -> Result<bool> {
+    let multisigner = derive_multisigner(seed, encryption, path)?;
+    same_account(
+        &print_multisigner_as_base58(&multisigner, None),
+        expected_ss58,
+    )
+}
+
+/// Derive [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// from the seed along the derivation path, see [`derive_public`]
+#[cfg(feature = "signer")]
+fn derive_multisigner(seed: &[u8], encryption: &Encryption, path: &str) -> Result<MultiSigner> {
+    let junctions = parse_derivation_path(path)?;
+    Ok(match encryption {
+        Encryption::Ed25519 => {
+            MultiSigner::Ed25519(derive_public::<ed25519::Pair>(seed, &junctions)?)
+        }
+        Encryption::Sr25519 => {
+            MultiSigner::Sr25519(derive_public::<sr25519::Pair>(seed, &junctions)?)
+        }
+        Encryption::Ecdsa => MultiSigner::Ecdsa(derive_public::<ecdsa::Pair>(seed, &junctions)?),
+    })
+}
+
+/// Check if two
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)s
+/// have same encryption and same public key
+pub fn multisigners_same_public(a: &MultiSigner, b: &MultiSigner) -> bool {
+    multisigner_to_encryption(a) == multisigner_to_encryption(b)
+        && multisigner_to_public(a) == multisigner_to_public(b)
+}
+
+/// Sort multisig signatories in canonical order, by bytes of their
+/// [`AccountId32`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/struct.AccountId32.html)
+///
+/// This is the order expected by `Multisig` pallet. Account id of `Ecdsa`
+/// signatory is `blake2_256` hash of its public key.
+pub fn sort_signatories(signers: &mut [MultiSigner]) {
+    signers.sort_by_cached_key(|m| <[u8; 32]>::from(m.clone().into_account()));
+}
+
+/// Find derivation path, for which the seed produces given
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+///
+/// Paths are tried in order, with the encryption of the `MultiSigner`. Paths
+/// that could not be parsed or used for derivation are skipped.
+#[cfg(feature = "signer")]
+pub fn belongs_to_seed(multisigner: &MultiSigner, seed: &[u8], paths: &[&str]) -> Option<String> {
+    let encryption = multisigner_to_encryption(multisigner);
+    paths
+        .iter()
+        .find(|path| match derive_multisigner(seed, &encryption, path) {
+            Ok(derived) => multisigners_same_public(&derived, multisigner),
+            Err(_) => false,
+        })
+        .map(|path| path.to_string())
+}
+
+/// Derive addresses for hard derivation paths `//0`, `//1`, up to `count`
+/// paths, for account discovery
+///
+/// Returns pairs of derivation path and address. Addresses are base58 with
+/// given prefix for `Ed25519` and `Sr25519`, and EIP-55 checksummed
+/// Ethereum addresses for `Ecdsa`, in which case prefix is not used.
+///
+/// Seed is not copied, derived secrets are wiped after each derivation.
+#[cfg(feature = "signer")]
+pub fn derivation_sweep(
+    seed: &[u8],
+    encryption: &Encryption,
+    count: u32,
+    prefix: Option<u16>,
+) -> Result<Vec<(String, String)>> {
+    (0..count)
+        .map(|index| {
+            let path = format!("//{}", index);
+            let address = match derive_multisigner(seed, encryption, &path)? {
+                MultiSigner::Ecdsa(public) => {
+                    checksum_eth_address(&ecdsa_public_to_eth_address(&public)?)
+                }
+                multisigner => print_multisigner_as_base58(&multisigner, prefix),
+            };
+            Ok((path, address))
+        })
+        .collect()
+}
+
+/// Derive new account from the seed along the derivation path and print its
+/// base58 address and PNG identicon of given size, for preview before the
+/// account is created
+///
+/// Seed is not copied, derived secret is wiped, see [`derive_public`].
+#[cfg(feature = "signer")]
+pub fn preview_new_account(
+    seed: &[u8],
+    encryption: &Encryption,
+    path: &str,
+    prefix: Option<u16>,
+    size: u16,
+) -> Result<(String, Vec<u8>)> {
+    let multisigner = derive_multisigner(seed, encryption, path)?;
+    Ok((
+        print_multisigner_as_base58(&multisigner, prefix),
+        make_identicon_sized(&identicon_seed(&multisigner), size)?,
+    ))
+}
+
+/// Get pure proxy account, that would be created by `Proxy` pallet
+/// `create_pure` (formerly `anonymous`) call of the `delegator` account
+///
+/// Pure proxy account is `blake2_256` hash of SCALE-encoded
+/// `modlpy/proxy____` identifier, delegator account, number of the block
+/// and index of the extrinsic in the block, in which the call was made,
+/// proxy type and disambiguation index.
+pub fn proxy_account(
+    delegator: &AccountId32,
+    block_number: u32,
+    extrinsic_index: u32,
+    proxy_type: u8,
+    index: u16,
+) -> AccountId32 {
+    AccountId32::new(blake2_256(
+        &(
+            b"modlpy/proxy____",
+            delegator,
+            block_number,
+            extrinsic_index,
+            proxy_type,
+            index,
+        )
+            .encode(),
+    ))
+}
+
+/// Pack recoverable `Ecdsa` signature components into 65-byte signature,
+/// `r` followed by `s` and recovery id `v`
+pub fn pack_signature(r: &[u8; 32], s: &[u8; 32], v: u8) -> [u8; 65] {
+    let mut signature = [0; 65];
+    signature[..32].copy_from_slice(r);
+    signature[32..64].copy_from_slice(s);
+    signature[64] = v;
+    signature
+}
+
+/// Split 65-byte recoverable `Ecdsa` signature into `r`, `s` and recovery id
+/// `v`, inverse of [`pack_signature`]
+pub fn unpack_signature(signature: &[u8; 65]) -> ([u8; 32], [u8; 32], u8) {
+    let mut r = [0; 32];
+    let mut s = [0; 32];
+    r.copy_from_slice(&signature[..32]);
+    s.copy_from_slice(&signature[32..64]);
+    (r, s, signature[64])
+}
+
+/// Entry point for fuzzing the decoding of untrusted input
+///
+/// Input is decoded as hexadecimal string, as public key for each encryption
+/// algorithm, and as base58 address. Results are discarded, none of the
+/// decoding paths is expected to panic on any input.
+#[cfg(feature = "fuzzing")]
+pub fn fuzz_decode_entry(data: &[u8]) {
+    let text = String::from_utf8_lossy(data);
+    let _ = unhex(&text);
+    for encryption in [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa] {
+        let _ = get_multisigner(data, &encryption);
+    }
+    let _ = decode_ss58(&text);
+}
+
 /// Print id pic for metadata hash
 ///
 /// Currently uses PNG identicon generator, could be changed later.
@@ -151,3 +1050,886 @@ pub fn pic_meta(meta_hash: &[u8]) -> Vec<u8> {
 pub fn pic_types(types_hash: &[u8]) -> Vec<u8> {
     make_identicon(types_hash)
 }
+
+/// Check that seed phrase has BIP39 word count, and get the word count
+///
+/// Only the number of words is checked, the words themselves and the
+/// checksum are not.
+pub fn validate_mnemonic_wordcount(phrase: &str) -> Result<usize> {
+    match phrase.split_whitespace().count() {
+        a @ (12 | 15 | 18 | 21 | 24) => Ok(a),
+        a => Err(Error::InvalidMnemonic(a)),
+    }
+}
+
+/// Clean up pasted secret URI
+///
+/// Leading and trailing whitespace is removed, and words of the seed phrase
+/// are separated by single spaces. Derivation path and password are kept as
+/// is. Secret URI must have non-empty seed phrase, derivation path without
+/// whitespace, and non-empty password if `///` is present.
+#[cfg(feature = "signer")]
+pub fn sanitize_secret_uri(input: &str) -> Result<Redacted<String>> {
+    let trimmed = input.trim();
+    let (phrase, path_and_password) = trimmed.split_at(trimmed.find('/').unwrap_or(trimmed.len()));
+    let (path, password) = match path_and_password.find("///") {
+        Some(i) => (&path_and_password[..i], Some(&path_and_password[i + 3..])),
+        None => (path_and_password, None),
+    };
+    if path.contains(char::is_whitespace)
+        || parse_derivation_path(path).is_err()
+        || password == Some("")
+    {
+        return Err(Error::InvalidSecretUri);
+    }
+
+    // allocated once, so that no partial copies of the secret are left behind
+    // on reallocation, and wrapped right away, so that it is wiped on drop
+    let mut sanitized = Redacted::new(String::with_capacity(trimmed.len()));
+    let buffer = sanitized.expose_mut();
+    for word in phrase.split_whitespace() {
+        if !buffer.is_empty() {
+            buffer.push(' ');
+        }
+        buffer.push_str(word);
+    }
+    if buffer.is_empty() {
+        return Err(Error::InvalidSecretUri);
+    }
+    buffer.push_str(path_and_password);
+    Ok(sanitized)
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+    use crate::ss58::{account_id_from_ss58, account_id_to_ss58};
+    use crate::test_values::{alice_ecdsa, alice_sr25519};
+
+    #[test]
+    fn public_from_bytes_checked_lengths() {
+        for encryption in [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa] {
+            let len = encryption.public_key_len();
+            assert!(matches!(
+                public_from_bytes_checked(&[], &encryption),
+                Err(Error::WrongPublicKeyLength)
+            ));
+            assert_eq!(
+                public_from_bytes_checked(&vec![7; len], &encryption).unwrap(),
+                vec![7; len]
+            );
+            assert!(matches!(
+                public_from_bytes_checked(&vec![7; len + 1], &encryption),
+                Err(Error::WrongPublicKeyLength)
+            ));
+        }
+    }
+
+    #[test]
+    fn derivation_path() {
+        assert!(parse_derivation_path("").unwrap().is_empty());
+        assert_eq!(
+            parse_derivation_path("//Alice/soft//1")
+                .unwrap()
+                .iter()
+                .map(|j| j.derive_junction)
+                .collect::<Vec<_>>(),
+            vec![
+                DeriveJunction::hard("Alice"),
+                DeriveJunction::soft("soft"),
+                DeriveJunction::hard(1u64),
+            ]
+        );
+        for path in ["Alice", "//", "//Alice/", "///password"] {
+            assert!(matches!(
+                parse_derivation_path(path),
+                Err(Error::InvalidDerivationPath)
+            ));
+        }
+    }
+
+    #[test]
+    fn seed_matches_address() {
+        // mini secret of development seed phrase
+        let seed =
+            unhex("fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e").unwrap();
+        assert!(verify_seed_matches_address(
+            &seed,
+            &Encryption::Sr25519,
+            "//Alice",
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        )
+        .unwrap());
+        assert!(verify_seed_matches_address(
+            &seed,
+            &Encryption::Ecdsa,
+            "//Alice",
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy"
+        )
+        .unwrap());
+        assert!(!verify_seed_matches_address(
+            &seed,
+            &Encryption::Sr25519,
+            "//Bob",
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn multisigner_builder_point_validation() {
+        // correct length, but `x` does not correspond to any curve point
+        let off_curve =
+            hex::decode("020000000000000000000000000000000000000000000000000000000000000005")
+                .unwrap();
+        assert!(MultisignerBuilder::new()
+            .build(&off_curve, &Encryption::Ecdsa)
+            .is_ok());
+        assert!(matches!(
+            MultisignerBuilder::new()
+                .validate_point(true)
+                .build(&off_curve, &Encryption::Ecdsa),
+            Err(Error::PublicKeyNotOnCurve)
+        ));
+
+        let alice =
+            hex::decode("020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1")
+                .unwrap();
+        assert_eq!(
+            MultisignerBuilder::new()
+                .validate_point(true)
+                .build(&alice, &Encryption::Ecdsa)
+                .unwrap(),
+            get_multisigner(&alice, &Encryption::Ecdsa).unwrap()
+        );
+    }
+
+    #[test]
+    fn fuzz_decode_entry_does_not_panic() {
+        // simple linear congruential generator, for reproducible input
+        let mut state: u32 = 0x2545_f491;
+        for len in 0..64 {
+            for _ in 0..16 {
+                let data: Vec<u8> = (0..len)
+                    .map(|_| {
+                        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                        (state >> 16) as u8
+                    })
+                    .collect();
+                fuzz_decode_entry(&data);
+            }
+        }
+        fuzz_decode_entry(b"0x");
+        fuzz_decode_entry("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".as_bytes());
+    }
+
+    #[test]
+    fn tagged_public() {
+        let sr25519_public =
+            hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap();
+        let ecdsa_public =
+            hex::decode("020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1")
+                .unwrap();
+        assert!(matches!(
+            TaggedPublic::new(Encryption::Ecdsa, &sr25519_public),
+            Err(Error::WrongPublicKeyLength)
+        ));
+        assert!(matches!(
+            TaggedPublic::new(Encryption::Sr25519, &ecdsa_public),
+            Err(Error::WrongPublicKeyLength)
+        ));
+        assert!(matches!(
+            TaggedPublic::new(Encryption::Ecdsa, &[0x05; 33]),
+            Err(Error::InvalidPublicKeyPoint(0x05))
+        ));
+
+        let tagged = TaggedPublic::new(Encryption::Sr25519, &sr25519_public).unwrap();
+        assert_eq!(tagged.bytes(), sr25519_public);
+        assert_eq!(
+            tagged.to_multisigner(),
+            get_multisigner(&sr25519_public, &Encryption::Sr25519).unwrap()
+        );
+        assert_eq!(
+            tagged.to_base58(None),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        assert!(matches!(
+            tagged.to_eth_address(),
+            Err(Error::NotSecp256k1Key)
+        ));
+
+        let tagged = TaggedPublic::new(Encryption::Ecdsa, &ecdsa_public).unwrap();
+        assert_eq!(tagged.encryption(), &Encryption::Ecdsa);
+        assert_eq!(
+            tagged.to_base58(Some(0)),
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy"
+        );
+        assert_eq!(
+            checksum_eth_address(&tagged.to_eth_address().unwrap()),
+            "0xE04CC55ebEE1cBCE552f250e85c57B70B2E2625b"
+        );
+    }
+
+    #[test]
+    fn multisignature_encryption() {
+        let signatures = [
+            (
+                MultiSignature::Ed25519(ed25519::Signature::from_raw([0; 64])),
+                Encryption::Ed25519,
+            ),
+            (
+                MultiSignature::Sr25519(sr25519::Signature::from_raw([0; 64])),
+                Encryption::Sr25519,
+            ),
+            (
+                MultiSignature::Ecdsa(ecdsa::Signature::from_raw([0; 65])),
+                Encryption::Ecdsa,
+            ),
+        ];
+        for (signature, encryption) in signatures.iter() {
+            assert_eq!(&multisignature_to_encryption(signature), encryption);
+        }
+    }
+
+    #[test]
+    fn hex_prefix_policy() {
+        for entry in ["0xabcd", "abcd"] {
+            let stripped = Hex0x::strip(entry);
+            assert_eq!(stripped, "abcd");
+            assert_eq!(Hex0x::strip(stripped), stripped);
+            assert_eq!(Hex0x::ensure(entry), "0xabcd");
+            assert_eq!(Hex0x::ensure(&Hex0x::ensure(entry)), "0xabcd");
+        }
+        assert!(matches!(Hex0x::ensure("0xabcd"), Cow::Borrowed(_)));
+        assert_eq!(Hex0x::strip("0x0xabcd"), "0xabcd");
+    }
+
+    #[test]
+    fn long_junction_is_hashed() {
+        let short = "a".repeat(10);
+        let junctions = parse_derivation_path(&format!("//{}", short)).unwrap();
+        assert_eq!(junctions.len(), 1);
+        assert!(!junctions[0].hashed);
+        let mut expected_id = [0; 32];
+        let encoded = short.encode();
+        expected_id[..encoded.len()].copy_from_slice(&encoded);
+        assert_eq!(
+            junctions[0].derive_junction,
+            DeriveJunction::Hard(expected_id)
+        );
+
+        let long = "a".repeat(100);
+        let junctions = parse_derivation_path(&format!("//{}", long)).unwrap();
+        assert_eq!(junctions.len(), 1);
+        assert!(junctions[0].hashed);
+        assert_eq!(
+            junctions[0].derive_junction,
+            DeriveJunction::Hard(sp_core::blake2_256(&long.encode()))
+        );
+    }
+
+    #[test]
+    fn public_key_hex_display() {
+        let ed25519_public = "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee";
+        let multisigner =
+            get_multisigner(&hex::decode(ed25519_public).unwrap(), &Encryption::Ed25519).unwrap();
+        assert_eq!(public_key_hex(&multisigner, false), ed25519_public);
+        assert_eq!(
+            public_key_hex(&multisigner, true),
+            format!("0x{}", ed25519_public)
+        );
+
+        let ecdsa_public = "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1";
+        let multisigner = alice_ecdsa();
+        assert_eq!(public_key_hex(&multisigner, false).len(), 66);
+        assert_eq!(
+            public_key_hex(&multisigner, true),
+            format!("0x{}", ecdsa_public)
+        );
+    }
+
+    #[test]
+    fn well_known_accounts() {
+        let zero = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        assert_eq!(well_known_account(&zero), Some("Zero account"));
+        let treasury =
+            account_id_from_ss58("5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z").unwrap();
+        let treasury = MultiSigner::Sr25519(sr25519::Public::from_raw(treasury.into()));
+        assert_eq!(well_known_account(&treasury), Some("Treasury"));
+        let alice = alice_sr25519();
+        assert_eq!(well_known_account(&alice), None);
+    }
+
+    #[test]
+    fn verifier_record() {
+        let publics = [
+            (
+                "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee",
+                Encryption::Ed25519,
+            ),
+            (
+                "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d",
+                Encryption::Sr25519,
+            ),
+            (
+                "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1",
+                Encryption::Ecdsa,
+            ),
+        ];
+        for (public, encryption) in publics {
+            let m = get_multisigner(&hex::decode(public).unwrap(), &encryption).unwrap();
+            let encoded = encode_verifier(&m, &encryption);
+            assert_eq!(decode_verifier(&encoded).unwrap(), (m, encryption));
+            for len in 0..encoded.len() {
+                assert!(matches!(
+                    decode_verifier(&encoded[..len]),
+                    Err(Error::UnexpectedEndOfData { .. })
+                ));
+            }
+            let mut extended = encoded.to_vec();
+            extended.push(0);
+            assert!(matches!(
+                decode_verifier(&extended),
+                Err(Error::TrailingBytes { left: 1 })
+            ));
+        }
+        assert!(matches!(
+            decode_verifier(&[3; 34]),
+            Err(Error::UnknownVerifierEncryption(3))
+        ));
+
+        let m = get_multisigner(&[0; 32], &Encryption::Sr25519).unwrap();
+        assert!(matches!(
+            decode_verifier(&encode_verifier(&m, &Encryption::Ed25519)),
+            Err(Error::VerifierEncryptionMismatch)
+        ));
+    }
+
+    #[test]
+    fn sr25519_signature_with_context() {
+        let keypair = schnorrkel::MiniSecretKey::from_bytes(&[7; 32])
+            .unwrap()
+            .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+        let public = sr25519::Public::from_raw(keypair.public.to_bytes());
+        let message = b"message";
+        let signature = keypair.sign_simple(b"custom", message).to_bytes();
+        assert!(verify_sr25519_with_context(
+            &public, b"custom", message, &signature
+        ));
+        assert!(!verify_sr25519_with_context(
+            &public,
+            b"substrate",
+            message,
+            &signature
+        ));
+        assert!(!verify_sr25519_with_context(
+            &public,
+            b"custom",
+            b"other message",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn any_pair_signs() {
+        use sp_runtime::traits::{IdentifyAccount, Verify};
+
+        let message = b"message";
+        for encryption in [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa] {
+            let pair = AnyPair::from_seed(&encryption, &[7; 32]).unwrap();
+            assert_eq!(pair.encryption(), encryption);
+            let signature = pair.sign(message);
+            assert_eq!(multisignature_to_encryption(&signature), encryption);
+            let account = pair.public().into_account();
+            assert!(signature.verify(&message[..], &account));
+            assert!(!signature.verify(&b"other message"[..], &account));
+        }
+        assert!(matches!(
+            AnyPair::from_seed(&Encryption::Ed25519, &[7; 31]),
+            Err(Error::KeyDerivation)
+        ));
+    }
+
+    #[test]
+    fn signature_packing() {
+        let r = [1; 32];
+        let s = [2; 32];
+        let packed = pack_signature(&r, &s, 27);
+        assert_eq!(&packed[..32], &r);
+        assert_eq!(&packed[32..64], &s);
+        assert_eq!(packed[64], 27);
+        assert_eq!(unpack_signature(&packed), (r, s, 27));
+        assert_eq!(
+            pack_signature(&r, &s, 1).len(),
+            Encryption::Ecdsa.signature_len()
+        );
+    }
+
+    #[test]
+    fn twox_hashes() {
+        assert_eq!(
+            hex::encode(twox_128(b"")),
+            "99e9d85137db46ef4bbea33613baafd5"
+        );
+        assert_eq!(
+            hex::encode(twox_256(b"")),
+            "99e9d85137db46ef4bbea33613baafd56f963c64b1f3685a4eb4abd67ff6203a"
+        );
+        // storage prefix of `System` pallet `Account` map
+        assert_eq!(
+            hex::encode([twox_128(b"System"), twox_128(b"Account")].concat()),
+            "26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9"
+        );
+    }
+
+    #[test]
+    fn find_seed_path() {
+        // mini secret of development seed phrase
+        let seed =
+            unhex("fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e").unwrap();
+        let alice = alice_sr25519();
+        assert_eq!(
+            belongs_to_seed(&alice, &seed, &["", "//Bob", "not a path", "//Alice"]),
+            Some(String::from("//Alice"))
+        );
+        assert_eq!(belongs_to_seed(&alice, &seed, &["", "//Bob", "//1"]), None);
+    }
+
+    #[test]
+    fn fingerprints() {
+        let alice = alice_sr25519();
+        let zero = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        let fingerprint = account_fingerprint(&alice);
+        assert_eq!(fingerprint.len(), 8);
+        assert_eq!(fingerprint, account_fingerprint(&alice.clone()));
+        assert_ne!(fingerprint, account_fingerprint(&zero));
+    }
+
+    #[test]
+    fn address_entry_integrity() {
+        let alice = alice_sr25519();
+        let genesis_hash = H256([1; 32]);
+        let hash = address_entry_hash(&alice, &Encryption::Sr25519, &genesis_hash, "//Alice");
+        assert!(verify_address_entry_hash(
+            &alice,
+            &Encryption::Sr25519,
+            &genesis_hash,
+            "//Alice",
+            &hash
+        ));
+        assert!(!verify_address_entry_hash(
+            &alice,
+            &Encryption::Sr25519,
+            &genesis_hash,
+            "//Bob",
+            &hash
+        ));
+        assert!(!verify_address_entry_hash(
+            &alice,
+            &Encryption::Sr25519,
+            &H256([2; 32]),
+            "//Alice",
+            &hash
+        ));
+    }
+
+    #[test]
+    fn sweep_derivations() {
+        // mini secret of development seed phrase
+        let seed =
+            unhex("fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e").unwrap();
+        let sweep = derivation_sweep(&seed, &Encryption::Ecdsa, 3, None).unwrap();
+        assert_eq!(sweep.len(), 3);
+        assert_eq!(
+            sweep[0],
+            (
+                String::from("//0"),
+                String::from("0x3c4E496295A2cd8F39012BaAd8634b21ddEb0ee2")
+            )
+        );
+        assert_eq!(sweep[2].0, "//2");
+
+        let sweep = derivation_sweep(&seed, &Encryption::Sr25519, 5, Some(0)).unwrap();
+        assert_eq!(sweep.len(), 5);
+        assert!(sweep.iter().all(|(_, address)| address.starts_with('1')));
+    }
+
+    #[test]
+    fn sanitize_pasted_secret_uri() {
+        let clean = "bottom drive obey lake curtain smoke basket hold race lonely fit walk//Alice";
+        let messy =
+            "  bottom drive obey  lake curtain\tsmoke basket hold race lonely fit   walk//Alice \n";
+        let sanitized = sanitize_secret_uri(messy).unwrap();
+        assert_eq!(
+            sr25519::Pair::from_string(sanitized.expose(), None)
+                .unwrap()
+                .public(),
+            sr25519::Pair::from_string(clean, None).unwrap().public()
+        );
+        assert_eq!(format!("{:?}", sanitized), "<redacted>");
+
+        let with_password =
+            sanitize_secret_uri(&format!("{}///secret password", messy.trim())).unwrap();
+        assert_eq!(
+            sr25519::Pair::from_string(with_password.expose(), None)
+                .unwrap()
+                .public(),
+            sr25519::Pair::from_string(&format!("{}///secret password", clean), None)
+                .unwrap()
+                .public()
+        );
+
+        for malformed in [
+            "   ",
+            "//Alice",
+            "bottom drive//Al ice",
+            "bottom drive//Alice///",
+        ] {
+            assert!(matches!(
+                sanitize_secret_uri(malformed),
+                Err(Error::InvalidSecretUri)
+            ));
+        }
+    }
+
+    #[test]
+    fn compressed_key_prefix() {
+        let mut public = [0; 33];
+        for prefix in [0x02, 0x03] {
+            public[0] = prefix;
+            assert!(validate_compressed_prefix(&public).is_ok());
+            assert!(get_multisigner(&public, &Encryption::Ecdsa).is_ok());
+        }
+        public[0] = 0x05;
+        assert!(matches!(
+            validate_compressed_prefix(&public),
+            Err(Error::InvalidPublicKeyPoint(0x05))
+        ));
+        assert!(matches!(
+            get_multisigner(&public, &Encryption::Ecdsa),
+            Err(Error::InvalidPublicKeyPoint(0x05))
+        ));
+    }
+
+    #[test]
+    fn pure_proxy_account() {
+        let alice =
+            account_id_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+        let proxy = proxy_account(&alice, 100, 1, 0, 0);
+        assert_eq!(
+            account_id_to_ss58(&proxy, Some(42)),
+            "5CXe15GjPv6EVMyXnHHRUL1nF5Fj2vCYX746y8SsgXnALh7Z"
+        );
+        assert_ne!(proxy, proxy_account(&alice, 100, 1, 0, 1));
+    }
+
+    #[test]
+    fn signatories_order() {
+        let alice = alice_sr25519();
+        let ecdsa_alice = alice_ecdsa();
+        let zero = MultiSigner::Ed25519(ed25519::Public::from_raw([0; 32]));
+        let mut signers = vec![alice.clone(), ecdsa_alice.clone(), zero.clone()];
+        sort_signatories(&mut signers);
+
+        // account id of ecdsa Alice is `blake2_256` hash of public key,
+        // `01e552298e47454041ea31273b4b630c64c104e4514aa3643490b8aaca9cf8ed`
+        let expected = vec![zero, ecdsa_alice, alice];
+        assert_eq!(signers, expected);
+        sort_signatories(&mut signers);
+        assert_eq!(signers, expected);
+    }
+
+    #[test]
+    fn transaction_signature() {
+        let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let signer = MultiSigner::Sr25519(pair.public());
+
+        let short_payload = [1; 100];
+        let signature = pair.sign(&short_payload);
+        assert!(verify_tx_signature(&signer, &short_payload, &signature.0, true).unwrap());
+
+        let long_payload = [2; 300];
+        let signature = pair.sign(&blake2_256(&long_payload));
+        assert!(verify_tx_signature(&signer, &long_payload, &signature.0, true).unwrap());
+        assert!(!verify_tx_signature(&signer, &long_payload, &signature.0, false).unwrap());
+
+        let mut tampered = long_payload;
+        tampered[0] = 3;
+        assert!(!verify_tx_signature(&signer, &tampered, &signature.0, true).unwrap());
+
+        assert!(matches!(
+            verify_tx_signature(&signer, &long_payload, &signature.0[..63], true),
+            Err(Error::WrongSignatureLength)
+        ));
+    }
+
+    #[test]
+    fn soft_only_paths() {
+        assert!(is_soft_only("/a/b").unwrap());
+        assert!(!is_soft_only("//a/b").unwrap());
+        assert!(is_soft_only("").unwrap());
+        assert!(matches!(
+            is_soft_only("a/b"),
+            Err(Error::InvalidDerivationPath)
+        ));
+    }
+
+    #[test]
+    fn fixed_size_hex() {
+        let genesis_hash = "0x91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3";
+        assert_eq!(
+            unhex_fixed::<32>(genesis_hash).unwrap(),
+            unhex(genesis_hash).unwrap()[..]
+        );
+        assert!(matches!(
+            unhex_fixed::<32>("91b171bb"),
+            Err(Error::WrongHashLength {
+                expected: 32,
+                got: 4
+            })
+        ));
+        assert!(matches!(
+            unhex_fixed::<4>("91b171bz"),
+            Err(Error::HexDecodingError(_))
+        ));
+    }
+
+    #[test]
+    fn multisigner_description() {
+        let ed25519_public = "88dc3417d5058ec4b4503e0c12ea1a0a89be200fe98922423d4334014fa6b0ee";
+        let sr25519_public = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+        let ecdsa_public = "020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1";
+        for (public, encryption, expected) in [
+            (
+                ed25519_public,
+                Encryption::Ed25519,
+                format!("ed25519:0x{}", ed25519_public),
+            ),
+            (
+                sr25519_public,
+                Encryption::Sr25519,
+                format!("sr25519:0x{}", sr25519_public),
+            ),
+            (
+                ecdsa_public,
+                Encryption::Ecdsa,
+                format!("ecdsa:0x{}", ecdsa_public),
+            ),
+        ] {
+            let m = get_multisigner(&hex::decode(public).unwrap(), &encryption).unwrap();
+            assert_eq!(describe_multisigner(&m), expected);
+            assert_eq!(describe_multisigner(&m.clone()), expected);
+        }
+    }
+
+    #[test]
+    fn encryption_pairs() {
+        let multisigners = [
+            MultiSigner::Ed25519(ed25519::Public::from_raw([0; 32])),
+            MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32])),
+            MultiSigner::Ecdsa(ecdsa::Public::from_raw([2; 33])),
+        ];
+        let encryptions = [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa];
+        for (i, m) in multisigners.iter().enumerate() {
+            for (j, encryption) in encryptions.iter().enumerate() {
+                assert_eq!(encryption_valid_for(m, encryption), i == j);
+            }
+        }
+    }
+
+    #[test]
+    fn new_account_preview() {
+        // mini secret of development seed phrase
+        let seed =
+            unhex("fac7959dbfe72f052e5a0c3c8d6530f202b02fd8f9f5ca3580ec8deb7797479e").unwrap();
+        let (address, identicon) =
+            preview_new_account(&seed, &Encryption::Sr25519, "//Alice", Some(42), 72).unwrap();
+        assert_eq!(address, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+        assert!(!identicon.is_empty());
+        assert_ne!(identicon, EMPTY_PNG.to_vec());
+    }
+
+    #[test]
+    fn verifier_encryption_peek() {
+        for m in [
+            MultiSigner::Ed25519(ed25519::Public::from_raw([0; 32])),
+            MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32])),
+            MultiSigner::Ecdsa(ecdsa::Public::from_raw([2; 33])),
+        ] {
+            let encryption = multisigner_to_encryption(&m);
+            let encoded = encode_verifier(&m, &encryption);
+            assert_eq!(peek_verifier_encryption(&encoded).unwrap(), encryption);
+        }
+        assert!(matches!(
+            peek_verifier_encryption(&[]),
+            Err(Error::UnexpectedEndOfData { .. })
+        ));
+        assert!(matches!(
+            peek_verifier_encryption(&[3]),
+            Err(Error::UnknownVerifierEncryption(3))
+        ));
+    }
+
+    #[test]
+    fn account_sort_keys() {
+        let (_, alice_polkadot) =
+            decode_ss58("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5").unwrap();
+        let (_, alice_substrate) =
+            decode_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
+        let alice_polkadot = get_multisigner(&alice_polkadot, &Encryption::Sr25519).unwrap();
+        let alice_substrate = get_multisigner(&alice_substrate, &Encryption::Sr25519).unwrap();
+        assert_eq!(
+            account_sort_key(&alice_polkadot, &Encryption::Sr25519),
+            account_sort_key(&alice_substrate, &Encryption::Sr25519)
+        );
+
+        let zero = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        let zero_key = account_sort_key(&zero, &Encryption::Sr25519);
+        assert_ne!(
+            zero_key,
+            account_sort_key(&alice_polkadot, &Encryption::Sr25519)
+        );
+        assert!(zero_key < account_sort_key(&alice_polkadot, &Encryption::Sr25519));
+        assert_eq!(zero_key.len(), 33);
+    }
+
+    #[test]
+    fn mnemonic_word_count() {
+        let phrase = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+        assert_eq!(validate_mnemonic_wordcount(phrase).unwrap(), 12);
+        assert_eq!(
+            validate_mnemonic_wordcount(&[phrase, phrase].join(" ")).unwrap(),
+            24
+        );
+        assert!(matches!(
+            validate_mnemonic_wordcount(&format!("{} walk", phrase)),
+            Err(Error::InvalidMnemonic(13))
+        ));
+    }
+
+    #[test]
+    fn polkadotjs_account_export() {
+        let sr25519_export = r#"{
+            "encoded": "bm90IGEgcmVhbCBzZWNyZXQ=",
+            "encoding": {
+                "content": ["pkcs8", "sr25519"],
+                "type": ["scrypt", "xsalsa20-poly1305"],
+                "version": "3"
+            },
+            "address": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "meta": {"genesisHash": "", "name": "Alice", "whenCreated": 1660000000000}
+        }"#;
+        let account = parse_polkadotjs_account(sr25519_export).unwrap();
+        assert_eq!(
+            account.address,
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+        assert_eq!(account.name.as_deref(), Some("Alice"));
+        assert_eq!(
+            account.public,
+            TaggedPublic::new(
+                Encryption::Sr25519,
+                &hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                    .unwrap()
+            )
+            .unwrap()
+        );
+
+        let ethereum_export = r#"{
+            "encoded": "bm90IGEgcmVhbCBzZWNyZXQ=",
+            "encoding": {
+                "content": ["pkcs8", "ethereum"],
+                "type": ["scrypt", "xsalsa20-poly1305"],
+                "version": "3"
+            },
+            "address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "meta": {"name": "Eth account"}
+        }"#;
+        assert!(matches!(
+            parse_polkadotjs_account(ethereum_export),
+            Err(Error::UnsupportedPolkadotJsEncoding(scheme)) if scheme == "ethereum"
+        ));
+
+        assert!(matches!(
+            parse_polkadotjs_account("{\"address\": 1}"),
+            Err(Error::InvalidPolkadotJsExport(_))
+        ));
+    }
+
+    #[test]
+    fn signing_request_digests() {
+        let alice = alice_sr25519();
+        let zero = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        let digest = signing_request_digest(&alice, b"payload");
+        assert_eq!(digest, signing_request_digest(&alice, b"payload"));
+        assert_ne!(digest, signing_request_digest(&zero, b"payload"));
+        assert_ne!(digest, signing_request_digest(&alice, b"payloae"));
+    }
+
+    #[test]
+    fn merge_imported_with_seed_derived() {
+        let imported = vec![
+            // Alice with Polkadot prefix, same account as seed-derived one
+            String::from("15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+            String::from("5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z"),
+            String::from("5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z"),
+        ];
+        let seed_derived = vec![(
+            String::from("//Alice"),
+            String::from("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+        )];
+        assert_eq!(
+            merge_with_seed_accounts(&imported, &seed_derived),
+            vec![
+                MergedEntry {
+                    address: String::from("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+                    path: Some(String::from("//Alice")),
+                    signable: true,
+                },
+                MergedEntry {
+                    address: String::from("5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z"),
+                    path: None,
+                    signable: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn verification_codes() {
+        let alice = alice_sr25519();
+        for digits in [0, 6, 100] {
+            let code = verification_code(&alice, digits);
+            assert_eq!(code.len(), digits);
+            assert!(code.chars().all(|c| c.is_ascii_digit()));
+            assert_eq!(code, verification_code(&alice, digits));
+        }
+        assert!(verification_code(&alice, 100).starts_with(&verification_code(&alice, 6)));
+    }
+
+    #[test]
+    fn wrapped_bytes() {
+        for message in [b"".as_slice(), b"hello", b"<Bytes>"] {
+            let wrapped = wrap_bytes_for_signing(message);
+            assert_eq!(unwrap_bytes(&wrapped), Some(message));
+        }
+        assert_eq!(wrap_bytes_for_signing(b"hi"), b"<Bytes>hi</Bytes>");
+        assert_eq!(unwrap_bytes(b"hello"), None);
+        assert_eq!(unwrap_bytes(b"<Bytes>hello"), None);
+    }
+
+    #[test]
+    fn wrapped_message_signature() {
+        let pair = sr25519::Pair::from_string("//Alice", None).unwrap();
+        let signer = MultiSigner::Sr25519(pair.public());
+        let message = b"message from dapp";
+
+        let signature = pair.sign(&wrap_bytes_for_signing(message));
+        assert!(verify_wrapped_message(&signer, message, &signature.0).unwrap());
+
+        let raw_signature = pair.sign(message);
+        assert!(!verify_wrapped_message(&signer, message, &raw_signature.0).unwrap());
+    }
+}
@@ -0,0 +1,959 @@
+//! Identicon helpers
+//!
+//! Identicon variants beyond the plain one in [`helpers`](crate::helpers):
+//! sized, animated, badged, flagged and high contrast pictures, account cards,
+//! and the small RGBA canvas they are drawn on.
+
+#[cfg(feature = "signer")]
+use plot_icon::{generate_png, generate_svg, EMPTY_PNG};
+#[cfg(feature = "signer")]
+use sha2::{Digest, Sha256};
+use sp_core::blake2_256;
+#[cfg(feature = "signer")]
+use sp_core::H256;
+use sp_runtime::MultiSigner;
+
+#[cfg(feature = "signer")]
+use crate::crypto::Encryption;
+use crate::error::{Error, Result};
+use crate::helpers::multisigner_to_public;
+#[cfg(feature = "signer")]
+use crate::helpers::{
+    encryption_valid_for, make_identicon, multisigner_to_encryption, pic_meta,
+    print_multisigner_as_base58, public_key_hex,
+};
+#[cfg(feature = "signer")]
+use crate::ss58::decode_ss58;
+
+/// Get the bytes used as identicon generator input for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+///
+/// Identicon is generated from raw public key, 32 bytes for `Ed25519` and
+/// `Sr25519`, and 33 bytes (compressed key) for `Ecdsa`.
+pub fn identicon_seed(multisigner: &MultiSigner) -> Vec<u8> {
+    multisigner_to_public(multisigner)
+}
+
+/// Get two RGB colors for identicon background gradient for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+///
+/// Colors are taken from non-overlapping parts of `blake2_256` hash of the
+/// identicon seed, so the gradient is always the same for the same key.
+pub fn identicon_gradient(multisigner: &MultiSigner) -> ([u8; 3], [u8; 3]) {
+    let hash = blake2_256(&identicon_seed(multisigner));
+    ([hash[0], hash[1], hash[2]], [hash[16], hash[17], hash[18]])
+}
+
+/// Print identicon of given size, with error if the identicon could not be
+/// generated
+#[cfg(feature = "signer")]
+pub(crate) fn make_identicon_sized(into_id: &[u8], size: u16) -> Result<Vec<u8>> {
+    generate_png(into_id, size).map_err(|_| Error::IdenticonGeneration)
+}
+
+/// Output format of identicon image
+#[cfg(feature = "signer")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IdenticonFormat {
+    Png,
+    Svg,
+    WebP,
+}
+
+/// Print identicon from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// in requested format
+///
+/// `size` is the image size in pixels for `Png`. `Svg` is scalable and is
+/// returned as UTF-8 bytes regardless of size. `WebP` is not supported.
+#[cfg(feature = "signer")]
+pub fn make_identicon_fmt(
+    multisigner: &MultiSigner,
+    size: u16,
+    format: IdenticonFormat,
+) -> Result<Vec<u8>> {
+    let into_id = identicon_seed(multisigner);
+    match format {
+        IdenticonFormat::Png => make_identicon_sized(&into_id, size),
+        IdenticonFormat::Svg => Ok(generate_svg(&into_id).to_string().into_bytes()),
+        IdenticonFormat::WebP => Err(Error::UnsupportedIdenticonFormat),
+    }
+}
+
+/// Check that stored identicon PNG is the identicon of given size for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+///
+/// Identicon generation is deterministic, so the identicon is re-generated
+/// and compared byte by byte.
+#[cfg(feature = "signer")]
+pub fn identicon_matches(multisigner: &MultiSigner, size: u16, stored_png: &[u8]) -> bool {
+    match make_identicon_sized(&identicon_seed(multisigner), size) {
+        Ok(identicon) => identicon == stored_png,
+        Err(_) => false,
+    }
+}
+
+/// Print a sequence of related identicons for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html),
+/// for animations
+///
+/// Frame `0` is the usual identicon of given size. For each following frame
+/// the identicon seed is extended with frame index as 8 little endian bytes,
+/// so the sequence is always the same for the same `MultiSigner`.
+#[cfg(feature = "signer")]
+pub fn make_identicon_frames(multisigner: &MultiSigner, frames: usize, size: u16) -> Vec<Vec<u8>> {
+    let seed = identicon_seed(multisigner);
+    (0..frames)
+        .map(|index| {
+            let frame_seed = if index == 0 {
+                seed.to_vec()
+            } else {
+                [seed.to_vec(), (index as u64).to_le_bytes().to_vec()].concat()
+            };
+            make_identicon_sized(&frame_seed, size).unwrap_or_else(|_| EMPTY_PNG.to_vec())
+        })
+        .collect()
+}
+
+/// Print identicon of given size from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// together with SHA-256 hash of the PNG bytes
+///
+/// Hash could be used as a stable cache key for the identicon image.
+#[cfg(feature = "signer")]
+pub fn make_identicon_with_hash(multisigner: &MultiSigner, size: u16) -> (Vec<u8>, [u8; 32]) {
+    let png = make_identicon_sized(&identicon_seed(multisigner), size)
+        .unwrap_or_else(|_| EMPTY_PNG.to_vec());
+    let hash = Sha256::digest(&png).into();
+    (png, hash)
+}
+
+/// PNG file signature
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Get width and height of PNG image, e.g. stored identicon, from its
+/// `IHDR` chunk
+///
+/// Only the PNG signature and the `IHDR` chunk header are checked, the
+/// image data is not decoded.
+pub fn identicon_dimensions(png: &[u8]) -> Result<(u32, u32)> {
+    let header = png
+        .get(..24)
+        .ok_or_else(|| Error::PngDecoding(String::from("too short for PNG header")))?;
+    if header[..8] != PNG_SIGNATURE || &header[12..16] != b"IHDR" {
+        return Err(Error::PngDecoding(String::from("not a PNG image")));
+    }
+    let width = u32::from_be_bytes([header[16], header[17], header[18], header[19]]);
+    let height = u32::from_be_bytes([header[20], header[21], header[22], header[23]]);
+    Ok((width, height))
+}
+
+/// Short preview of an account, for list rows
+#[cfg(feature = "signer")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountPreview {
+    /// First 8 symbols of lowercase hexadecimal public key
+    pub short_hex: String,
+
+    /// Identicon PNG
+    pub identicon: Vec<u8>,
+}
+
+/// Get [`AccountPreview`] for
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html),
+/// with identicon of given size
+#[cfg(feature = "signer")]
+pub fn account_preview(multisigner: &MultiSigner, size: u16) -> AccountPreview {
+    let mut short_hex = public_key_hex(multisigner, false);
+    short_hex.truncate(8);
+    let identicon = make_identicon_sized(&identicon_seed(multisigner), size)
+        .unwrap_or_else(|_| EMPTY_PNG.to_vec());
+    AccountPreview {
+        short_hex,
+        identicon,
+    }
+}
+
+/// Print identicon from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// with network logo in the bottom right quadrant
+///
+/// Network logo could be any valid PNG image, it gets scaled to fit the
+/// quadrant and is drawn over the identicon with respect to logo transparency.
+#[cfg(feature = "signer")]
+pub fn make_badged_identicon(
+    multisigner: &MultiSigner,
+    network_logo: &[u8],
+    size: u16,
+) -> Result<Vec<u8>> {
+    let logo = RgbaImage::from_png(network_logo)?;
+    let mut identicon =
+        RgbaImage::from_png(&make_identicon_sized(&identicon_seed(multisigner), size)?)?;
+    let half_width = identicon.width / 2;
+    let half_height = identicon.height / 2;
+    identicon.draw_scaled(
+        &logo,
+        half_width,
+        half_height,
+        identicon.width - half_width,
+        identicon.height - half_height,
+    );
+    identicon.to_png()
+}
+
+/// Print identicon of given size from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// cropped to a circle
+///
+/// Pixels outside the circle inscribed into the image are fully transparent.
+#[cfg(feature = "signer")]
+pub fn make_identicon_circular(multisigner: &MultiSigner, size: u16) -> Vec<u8> {
+    let circular = make_identicon_sized(&identicon_seed(multisigner), size)
+        .and_then(|png| RgbaImage::from_png(&png))
+        .and_then(|mut image| {
+            image.mask_circle();
+            image.to_png()
+        });
+    circular.unwrap_or_else(|_| EMPTY_PNG.to_vec())
+}
+
+/// Color of the identicon background circle, as drawn by `plot_icon`
+pub const IDENTICON_BACKGROUND: [u8; 3] = [0xee, 0xee, 0xee];
+
+/// Color of the warning marker in [`make_identicon_flagged`]
+#[cfg(feature = "signer")]
+const FLAG_MARKER_COLOR: [u8; 4] = [0xe0, 0x20, 0x20, 0xff];
+
+/// Print identicon of given size from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// with red warning marker, e.g. for accounts derived with weak path
+///
+/// Marker is a right triangle in the top right corner, with legs a quarter of
+/// the identicon size.
+#[cfg(feature = "signer")]
+pub fn make_identicon_flagged(multisigner: &MultiSigner, size: u16) -> Result<Vec<u8>> {
+    let mut identicon =
+        RgbaImage::from_png(&make_identicon_sized(&identicon_seed(multisigner), size)?)?;
+    let side = (identicon.width.min(identicon.height) / 4).max(1);
+    identicon.draw_corner_triangle(side, FLAG_MARKER_COLOR);
+    identicon.to_png()
+}
+
+/// Print high-contrast identicon of given size from
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html),
+/// black dots on white background
+///
+/// Identicon is rendered as usual, then every opaque pixel that is not the
+/// [`IDENTICON_BACKGROUND`] becomes black, and all other pixels, including
+/// the transparent ones, become white. Dots stay at the same positions as in
+/// the normal identicon, regardless of the dot lightness.
+#[cfg(feature = "signer")]
+pub fn make_identicon_high_contrast(multisigner: &MultiSigner, size: u16) -> Vec<u8> {
+    let high_contrast = make_identicon_sized(&identicon_seed(multisigner), size)
+        .and_then(|png| RgbaImage::from_png(&png))
+        .and_then(|mut image| {
+            image.make_monochrome(IDENTICON_BACKGROUND);
+            image.to_png()
+        });
+    high_contrast.unwrap_or_else(|_| EMPTY_PNG.to_vec())
+}
+
+/// Gap between identicons in [`make_pair_identicon`], in pixels
+pub const PAIR_IDENTICON_GAP: u16 = 8;
+
+/// Print identicons of two
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)s
+/// side by side, e.g. for sender and recipient
+///
+/// Identicons of given size are placed left to right with
+/// [`PAIR_IDENTICON_GAP`] between them, on transparent background. Resulting
+/// image is `2 * size + PAIR_IDENTICON_GAP` wide.
+#[cfg(feature = "signer")]
+pub fn make_pair_identicon(left: &MultiSigner, right: &MultiSigner, size: u16) -> Result<Vec<u8>> {
+    let left = RgbaImage::from_png(&make_identicon_sized(&identicon_seed(left), size)?)?;
+    let right = RgbaImage::from_png(&make_identicon_sized(&identicon_seed(right), size)?)?;
+    let size = size as u32;
+    let mut pair = RgbaImage::transparent(2 * size + PAIR_IDENTICON_GAP as u32, size);
+    pair.draw_scaled(&left, 0, 0, size, size);
+    pair.draw_scaled(&right, size + PAIR_IDENTICON_GAP as u32, 0, size, size);
+    pair.to_png()
+}
+
+/// Symbols available in [`CARD_FONT`], in the order of glyphs
+#[cfg(feature = "signer")]
+const CARD_FONT_SYMBOLS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz.";
+
+/// Width of [`CARD_FONT`] glyph, in pixels
+#[cfg(feature = "signer")]
+const CARD_FONT_WIDTH: u32 = 6;
+
+/// Height of [`CARD_FONT`] glyph, in pixels, including two rows for
+/// descenders
+#[cfg(feature = "signer")]
+const CARD_FONT_HEIGHT: u32 = 10;
+
+/// Bitmap font for [`make_account_card`]
+///
+/// Glyphs are drawn for this crate, with common baseline.
+///
+/// Each glyph is a row-by-row bit mask, with the most significant of the
+/// [`CARD_FONT_WIDTH`] low bits being the leftmost pixel.
+#[cfg(feature = "signer")]
+#[rustfmt::skip]
+const CARD_FONT: [[u8; CARD_FONT_HEIGHT as usize]; 63] = [
+    [0x00, 0x0c, 0x12, 0x12, 0x16, 0x12, 0x12, 0x0c, 0x00, 0x00], // 0
+    [0x00, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0e, 0x00, 0x00], // 1
+    [0x00, 0x0c, 0x12, 0x02, 0x06, 0x0c, 0x18, 0x1e, 0x00, 0x00], // 2
+    [0x00, 0x0c, 0x12, 0x02, 0x0c, 0x02, 0x02, 0x1c, 0x00, 0x00], // 3
+    [0x00, 0x04, 0x04, 0x0c, 0x0c, 0x14, 0x1e, 0x04, 0x00, 0x00], // 4
+    [0x00, 0x1e, 0x10, 0x10, 0x1c, 0x02, 0x02, 0x1c, 0x00, 0x00], // 5
+    [0x00, 0x0e, 0x18, 0x10, 0x1e, 0x12, 0x12, 0x0c, 0x00, 0x00], // 6
+    [0x00, 0x1e, 0x02, 0x04, 0x04, 0x04, 0x0c, 0x08, 0x00, 0x00], // 7
+    [0x00, 0x0c, 0x12, 0x12, 0x0c, 0x12, 0x12, 0x0c, 0x00, 0x00], // 8
+    [0x00, 0x0c, 0x12, 0x12, 0x1e, 0x02, 0x06, 0x1c, 0x00, 0x00], // 9
+    [0x00, 0x0c, 0x0c, 0x0c, 0x12, 0x12, 0x1e, 0x12, 0x00, 0x00], // A
+    [0x00, 0x1c, 0x12, 0x12, 0x1c, 0x12, 0x12, 0x1e, 0x00, 0x00], // B
+    [0x00, 0x0e, 0x10, 0x10, 0x10, 0x10, 0x10, 0x0e, 0x00, 0x00], // C
+    [0x00, 0x1c, 0x12, 0x12, 0x12, 0x12, 0x12, 0x1c, 0x00, 0x00], // D
+    [0x00, 0x1e, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x1e, 0x00, 0x00], // E
+    [0x00, 0x1e, 0x10, 0x10, 0x1e, 0x10, 0x10, 0x10, 0x00, 0x00], // F
+    [0x00, 0x0e, 0x10, 0x10, 0x16, 0x12, 0x12, 0x0e, 0x00, 0x00], // G
+    [0x00, 0x12, 0x12, 0x12, 0x1e, 0x12, 0x12, 0x12, 0x00, 0x00], // H
+    [0x00, 0x1c, 0x08, 0x08, 0x08, 0x08, 0x08, 0x1c, 0x00, 0x00], // I
+    [0x00, 0x0e, 0x02, 0x02, 0x02, 0x02, 0x02, 0x1e, 0x00, 0x00], // J
+    [0x00, 0x12, 0x14, 0x18, 0x18, 0x14, 0x14, 0x12, 0x00, 0x00], // K
+    [0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1e, 0x00, 0x00], // L
+    [0x00, 0x12, 0x1e, 0x1e, 0x1e, 0x1e, 0x12, 0x12, 0x00, 0x00], // M
+    [0x00, 0x12, 0x1a, 0x1a, 0x16, 0x16, 0x16, 0x12, 0x00, 0x00], // N
+    [0x00, 0x0c, 0x12, 0x12, 0x12, 0x12, 0x12, 0x0c, 0x00, 0x00], // O
+    [0x00, 0x1c, 0x12, 0x12, 0x1c, 0x10, 0x10, 0x10, 0x00, 0x00], // P
+    [0x00, 0x0c, 0x12, 0x12, 0x12, 0x12, 0x12, 0x0c, 0x02, 0x00], // Q
+    [0x00, 0x1c, 0x12, 0x12, 0x1c, 0x16, 0x12, 0x11, 0x00, 0x00], // R
+    [0x00, 0x0c, 0x12, 0x10, 0x0e, 0x02, 0x12, 0x0c, 0x00, 0x00], // S
+    [0x00, 0x3e, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // T
+    [0x00, 0x12, 0x12, 0x12, 0x12, 0x12, 0x12, 0x0c, 0x00, 0x00], // U
+    [0x00, 0x12, 0x12, 0x0c, 0x0c, 0x0c, 0x0c, 0x0c, 0x00, 0x00], // V
+    [0x00, 0x22, 0x22, 0x2a, 0x36, 0x14, 0x14, 0x14, 0x00, 0x00], // W
+    [0x00, 0x12, 0x12, 0x0c, 0x0c, 0x0c, 0x12, 0x12, 0x00, 0x00], // X
+    [0x00, 0x22, 0x14, 0x14, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // Y
+    [0x00, 0x1e, 0x02, 0x04, 0x04, 0x08, 0x10, 0x1e, 0x00, 0x00], // Z
+    [0x00, 0x00, 0x00, 0x1c, 0x02, 0x1e, 0x12, 0x1e, 0x00, 0x00], // a
+    [0x10, 0x10, 0x10, 0x1c, 0x12, 0x12, 0x12, 0x1c, 0x00, 0x00], // b
+    [0x00, 0x00, 0x00, 0x0e, 0x10, 0x10, 0x10, 0x0e, 0x00, 0x00], // c
+    [0x02, 0x02, 0x02, 0x0e, 0x12, 0x12, 0x12, 0x0e, 0x00, 0x00], // d
+    [0x00, 0x00, 0x00, 0x0c, 0x12, 0x1e, 0x10, 0x0e, 0x00, 0x00], // e
+    [0x06, 0x08, 0x08, 0x1e, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // f
+    [0x00, 0x00, 0x00, 0x0e, 0x12, 0x12, 0x12, 0x0e, 0x02, 0x0c], // g
+    [0x10, 0x10, 0x10, 0x1e, 0x12, 0x12, 0x12, 0x12, 0x00, 0x00], // h
+    [0x08, 0x00, 0x00, 0x18, 0x08, 0x08, 0x08, 0x3e, 0x00, 0x00], // i
+    [0x04, 0x00, 0x00, 0x0c, 0x04, 0x04, 0x04, 0x04, 0x04, 0x1c], // j
+    [0x10, 0x10, 0x10, 0x12, 0x14, 0x18, 0x14, 0x12, 0x00, 0x00], // k
+    [0x38, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x06, 0x00, 0x00], // l
+    [0x00, 0x00, 0x00, 0x1f, 0x15, 0x15, 0x15, 0x15, 0x00, 0x00], // m
+    [0x00, 0x00, 0x00, 0x1e, 0x12, 0x12, 0x12, 0x12, 0x00, 0x00], // n
+    [0x00, 0x00, 0x00, 0x0c, 0x12, 0x12, 0x12, 0x0c, 0x00, 0x00], // o
+    [0x00, 0x00, 0x00, 0x1c, 0x12, 0x12, 0x12, 0x1c, 0x10, 0x10], // p
+    [0x00, 0x00, 0x00, 0x0e, 0x12, 0x12, 0x12, 0x0e, 0x02, 0x02], // q
+    [0x00, 0x00, 0x00, 0x0e, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00], // r
+    [0x00, 0x00, 0x00, 0x1e, 0x10, 0x0e, 0x02, 0x1e, 0x00, 0x00], // s
+    [0x00, 0x00, 0x08, 0x1e, 0x08, 0x08, 0x08, 0x0e, 0x00, 0x00], // t
+    [0x00, 0x00, 0x00, 0x12, 0x12, 0x12, 0x12, 0x1e, 0x00, 0x00], // u
+    [0x00, 0x00, 0x00, 0x12, 0x12, 0x0c, 0x0c, 0x0c, 0x00, 0x00], // v
+    [0x00, 0x00, 0x00, 0x22, 0x2a, 0x14, 0x14, 0x14, 0x00, 0x00], // w
+    [0x00, 0x00, 0x00, 0x12, 0x0c, 0x0c, 0x0c, 0x12, 0x00, 0x00], // x
+    [0x00, 0x00, 0x00, 0x12, 0x12, 0x0c, 0x0c, 0x08, 0x08, 0x18], // y
+    [0x00, 0x00, 0x00, 0x1e, 0x04, 0x0c, 0x08, 0x1e, 0x00, 0x00], // z
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00], // .
+];
+
+/// Number of address symbols kept on each side in [`make_account_card`]
+pub const ACCOUNT_CARD_ADDRESS_SIDE: usize = 8;
+
+/// Print account card of
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html):
+/// identicon of given size with shortened base58 address below it
+///
+/// Address is printed with network-specific base58 prefix if `prefix` is
+/// `Some(value)`, and with default one otherwise, and keeps
+/// [`ACCOUNT_CARD_ADDRESS_SIDE`] first and last symbols with `...` in
+/// between. Text is drawn in black with bundled bitmap font, scaled to the
+/// identicon size. Card has white background, and is wide enough for both
+/// the identicon and the text.
+#[cfg(feature = "signer")]
+pub fn make_account_card(
+    m: &MultiSigner,
+    encryption: &Encryption,
+    prefix: Option<u16>,
+    size: u16,
+) -> Result<Vec<u8>> {
+    if !encryption_valid_for(m, encryption) {
+        return Err(Error::MultiSignerEncryptionMismatch {
+            expected: encryption.to_owned(),
+            got: multisigner_to_encryption(m),
+        });
+    }
+    let address = print_multisigner_as_base58(m, prefix);
+    let text = if address.len() > 2 * ACCOUNT_CARD_ADDRESS_SIDE + 3 {
+        format!(
+            "{}...{}",
+            &address[..ACCOUNT_CARD_ADDRESS_SIDE],
+            &address[address.len() - ACCOUNT_CARD_ADDRESS_SIDE..]
+        )
+    } else {
+        address
+    };
+    let identicon = RgbaImage::from_png(&make_identicon_sized(&identicon_seed(m), size)?)?;
+
+    let size = size as u32;
+    let scale = (size / 64).max(1);
+    let margin = 2 * scale;
+    let text_width = text.len() as u32 * CARD_FONT_WIDTH * scale;
+    let width = size.max(text_width) + 2 * margin;
+    let height = size + CARD_FONT_HEIGHT * scale + 3 * margin;
+
+    let mut card = RgbaImage::transparent(width, height);
+    for pixel in card.pixels.chunks_exact_mut(4) {
+        pixel.copy_from_slice(&[0xff; 4]);
+    }
+    card.draw_scaled(&identicon, (width - size) / 2, margin, size, size);
+    card.draw_text(
+        &text,
+        (width - text_width) / 2,
+        size + 2 * margin,
+        scale,
+        [0, 0, 0, 0xff],
+    );
+    card.to_png()
+}
+
+/// Image with 8-bit RGBA pixels, for identicon post-processing
+#[cfg(feature = "signer")]
+struct RgbaImage {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+#[cfg(feature = "signer")]
+impl RgbaImage {
+    /// Fully transparent `RgbaImage`
+    fn transparent(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; 4 * width as usize * height as usize],
+        }
+    }
+
+    /// Decode PNG image of any color type into `RgbaImage`
+    fn from_png(png_data: &[u8]) -> Result<Self> {
+        let mut decoder = png::Decoder::new(png_data);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder
+            .read_info()
+            .map_err(|e| Error::PngDecoding(e.to_string()))?;
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut buffer)
+            .map_err(|e| Error::PngDecoding(e.to_string()))?;
+        let data = &buffer[..info.buffer_size()];
+        let pixels: Vec<u8> = match info.color_type {
+            png::ColorType::Rgba => data.to_vec(),
+            png::ColorType::Rgb => data
+                .chunks(3)
+                .flat_map(|p| [p[0], p[1], p[2], 255])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => data
+                .chunks(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => data.iter().flat_map(|p| [*p, *p, *p, 255]).collect(),
+            png::ColorType::Indexed => {
+                return Err(Error::PngDecoding(String::from(
+                    "indexed colors were not expanded",
+                )))
+            }
+        };
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    /// Encode `RgbaImage` as PNG
+    fn to_png(&self) -> Result<Vec<u8>> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| Error::PngEncoding(e.to_string()))?;
+        writer
+            .write_image_data(&self.pixels)
+            .map_err(|e| Error::PngEncoding(e.to_string()))?;
+        writer
+            .finish()
+            .map_err(|e| Error::PngEncoding(e.to_string()))?;
+        Ok(out)
+    }
+
+    /// Position of the pixel `(x, y)` in `pixels`
+    fn offset(&self, x: u32, y: u32) -> usize {
+        4 * (y as usize * self.width as usize + x as usize)
+    }
+
+    /// Draw `pixel` over the pixel `(x, y)`, with alpha blending
+    fn blend_pixel(&mut self, x: u32, y: u32, pixel: [u8; 4]) {
+        let offset = self.offset(x, y);
+        let dst = &mut self.pixels[offset..offset + 4];
+        let src_alpha = pixel[3] as u32;
+        let dst_alpha = dst[3] as u32 * (255 - src_alpha) / 255;
+        let out_alpha = src_alpha + dst_alpha;
+        for (dst_channel, src_channel) in dst.iter_mut().zip(pixel.iter()).take(3) {
+            *dst_channel = (*src_channel as u32 * src_alpha + *dst_channel as u32 * dst_alpha)
+                .checked_div(out_alpha)
+                .unwrap_or(0) as u8;
+        }
+        dst[3] = out_alpha as u8;
+    }
+
+    /// Replace pixels with opaque black or white
+    ///
+    /// Pixel becomes black if it is opaque and its color differs from
+    /// `background`, and white otherwise.
+    fn make_monochrome(&mut self, background: [u8; 3]) {
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            let value = if pixel[3] == 0xff && pixel[..3] != background {
+                0
+            } else {
+                0xff
+            };
+            pixel.copy_from_slice(&[value, value, value, 0xff]);
+        }
+    }
+
+    /// Make pixels outside the inscribed circle fully transparent
+    ///
+    /// Pixel is inside if its center is inside the circle.
+    fn mask_circle(&mut self) {
+        // coordinates are doubled, to keep pixel centers integer
+        let width = self.width as i64;
+        let height = self.height as i64;
+        let diameter = width.min(height);
+        for y in 0..height {
+            for x in 0..width {
+                let dx = 2 * x + 1 - width;
+                let dy = 2 * y + 1 - height;
+                if dx * dx + dy * dy > diameter * diameter {
+                    let offset = self.offset(x as u32, y as u32);
+                    self.pixels[offset + 3] = 0;
+                }
+            }
+        }
+    }
+
+    /// Draw right triangle with legs of `side` pixels in the top right
+    /// corner, with alpha blending
+    fn draw_corner_triangle(&mut self, side: u32, pixel: [u8; 4]) {
+        let side = side.min(self.width).min(self.height);
+        for y in 0..side {
+            for x in (self.width - side + y)..self.width {
+                self.blend_pixel(x, y, pixel);
+            }
+        }
+    }
+
+    /// Draw `image` scaled to `width` and `height` over the area starting at
+    /// `(x, y)`, with alpha blending
+    ///
+    /// Scaling is done with nearest neighbor sampling. Parts that do not fit
+    /// into current image are cut off.
+    fn draw_scaled(&mut self, image: &RgbaImage, x: u32, y: u32, width: u32, height: u32) {
+        for dy in 0..height {
+            for dx in 0..width {
+                if x + dx >= self.width || y + dy >= self.height {
+                    continue;
+                }
+                let offset = image.offset(dx * image.width / width, dy * image.height / height);
+                let pixel = [
+                    image.pixels[offset],
+                    image.pixels[offset + 1],
+                    image.pixels[offset + 2],
+                    image.pixels[offset + 3],
+                ];
+                self.blend_pixel(x + dx, y + dy, pixel);
+            }
+        }
+    }
+
+    /// Draw `text` with [`CARD_FONT`] glyphs scaled by `scale`, starting
+    /// with top left corner at `(x, y)`, with alpha blending
+    ///
+    /// Symbols missing from the font are left blank. Parts that do not fit
+    /// into current image are cut off.
+    fn draw_text(&mut self, text: &str, x: u32, y: u32, scale: u32, pixel: [u8; 4]) {
+        for (position, symbol) in text.chars().enumerate() {
+            let glyph = match CARD_FONT_SYMBOLS.find(symbol) {
+                Some(index) => CARD_FONT[index],
+                None => continue,
+            };
+            let left = x + position as u32 * CARD_FONT_WIDTH * scale;
+            for (row, bits) in glyph.iter().enumerate() {
+                for column in 0..CARD_FONT_WIDTH {
+                    if bits & (1 << (CARD_FONT_WIDTH - 1 - column)) == 0 {
+                        continue;
+                    }
+                    for dy in 0..scale {
+                        for dx in 0..scale {
+                            let px = left + column * scale + dx;
+                            let py = y + row as u32 * scale + dy;
+                            if px < self.width && py < self.height {
+                                self.blend_pixel(px, py, pixel);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Print identicon from base58 address, checking the address prefix
+///
+/// If `expected_prefix` is `Some(value)`, the address prefix must match it.
+#[cfg(feature = "signer")]
+pub fn make_identicon_validated_ss58(
+    address: &str,
+    expected_prefix: Option<u16>,
+) -> Result<Vec<u8>> {
+    let (prefix, public) = decode_ss58(address)?;
+    if let Some(expected) = expected_prefix {
+        if expected != prefix {
+            return Err(Error::UnexpectedPrefix {
+                expected,
+                got: prefix,
+            });
+        }
+    }
+    Ok(make_identicon(&public))
+}
+
+/// Print id pic for metadata hash, checking that the hash is 32 bytes long
+///
+/// Same as [`pic_meta`], but does not render pics for truncated hashes.
+#[cfg(feature = "signer")]
+pub fn pic_meta_checked(meta_hash: &[u8]) -> Result<Vec<u8>> {
+    if meta_hash.len() != 32 {
+        return Err(Error::WrongHashLength {
+            expected: 32,
+            got: meta_hash.len(),
+        });
+    }
+    Ok(pic_meta(meta_hash))
+}
+
+/// Print id pic for metadata hash of a specific network
+///
+/// Identicon is generated from metadata hash followed by network genesis
+/// hash, so that same metadata of different networks gets different pics.
+/// See [`pic_meta`] for pic of metadata hash only.
+#[cfg(feature = "signer")]
+pub fn pic_meta_for_network(meta_hash: &[u8], genesis_hash: &H256) -> Vec<u8> {
+    make_identicon(&[meta_hash, genesis_hash.as_bytes()].concat())
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+    use crate::helpers::{make_identicon_from_account, make_identicon_from_multisigner};
+    use crate::ss58::account_id_from_ss58;
+    use crate::test_values::{alice_ecdsa, alice_sr25519};
+    use hex;
+    use sp_core::sr25519;
+
+    #[test]
+    fn identicon_seed_is_raw_public() {
+        let multisigner = alice_ecdsa();
+        assert_eq!(
+            identicon_seed(&multisigner),
+            hex::decode("020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1")
+                .unwrap()
+        );
+        assert_eq!(
+            make_identicon_from_multisigner(&multisigner),
+            make_identicon(&identicon_seed(&multisigner))
+        );
+    }
+
+    #[test]
+    fn badged_identicon() {
+        let multisigner = alice_sr25519();
+        let logo = pic_meta(&[0; 32]);
+        let badged = make_badged_identicon(&multisigner, &logo, 72).unwrap();
+        let image = RgbaImage::from_png(&badged).unwrap();
+        assert_eq!((image.width, image.height), (72, 72));
+        let plain = RgbaImage::from_png(&make_identicon_from_multisigner(&multisigner)).unwrap();
+        assert_ne!(image.pixels, plain.pixels);
+    }
+
+    #[test]
+    fn badged_identicon_bad_logo() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        assert!(matches!(
+            make_badged_identicon(&multisigner, b"not a png", 72),
+            Err(Error::PngDecoding(_))
+        ));
+    }
+
+    #[test]
+    fn identicon_validated_ss58_matching_prefix() {
+        let address = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        let account = account_id_from_ss58(address).unwrap();
+        assert_eq!(
+            make_identicon_validated_ss58(address, Some(0)).unwrap(),
+            make_identicon_from_account(account.clone())
+        );
+        assert_eq!(
+            make_identicon_validated_ss58(address, None).unwrap(),
+            make_identicon_from_account(account)
+        );
+    }
+
+    #[test]
+    fn identicon_validated_ss58_mismatching_prefix() {
+        let address = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        assert!(matches!(
+            make_identicon_validated_ss58(address, Some(2)),
+            Err(Error::UnexpectedPrefix {
+                expected: 2,
+                got: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn identicon_frames() {
+        let multisigner = alice_sr25519();
+        let frames = make_identicon_frames(&multisigner, 4, 72);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames[0], make_identicon_from_multisigner(&multisigner));
+        assert_ne!(frames[0], frames[1]);
+        assert_eq!(frames, make_identicon_frames(&multisigner, 4, 72));
+    }
+
+    #[test]
+    fn identicon_with_hash() {
+        let multisigner = alice_sr25519();
+        let (png, hash) = make_identicon_with_hash(&multisigner, 72);
+        assert_eq!(hash, <[u8; 32]>::from(Sha256::digest(&png)));
+        assert_eq!(make_identicon_with_hash(&multisigner, 72), (png, hash));
+    }
+
+    #[test]
+    fn empty_png_is_transparent() {
+        // catches changes of `EMPTY_PNG` in `plot_icon` updates
+        let image = RgbaImage::from_png(&EMPTY_PNG).unwrap();
+        assert_eq!((image.width, image.height), (30, 30));
+        assert_eq!(image.pixels.len(), 30 * 30 * 4);
+        assert!(image.pixels.chunks(4).all(|pixel| pixel[3] == 0));
+    }
+
+    #[test]
+    fn network_specific_meta_pic() {
+        let meta_hash = [7; 32];
+        let pic_a = pic_meta_for_network(&meta_hash, &H256([1; 32]));
+        let pic_b = pic_meta_for_network(&meta_hash, &H256([2; 32]));
+        assert_ne!(pic_a, pic_b);
+        assert_eq!(pic_a, pic_meta_for_network(&meta_hash, &H256([1; 32])));
+        assert_ne!(pic_a, pic_meta(&meta_hash));
+    }
+
+    #[test]
+    fn preview_of_account() {
+        let multisigner = alice_sr25519();
+        let preview = account_preview(&multisigner, 72);
+        assert_eq!(preview.short_hex, "d43593c7");
+        assert!(!preview.identicon.is_empty());
+        assert_ne!(preview.identicon, EMPTY_PNG.to_vec());
+    }
+
+    #[test]
+    fn identicon_formats() {
+        let multisigner = alice_sr25519();
+        let png = make_identicon_fmt(&multisigner, 72, IdenticonFormat::Png).unwrap();
+        assert_eq!(png, make_identicon_from_multisigner(&multisigner));
+        let svg = make_identicon_fmt(&multisigner, 72, IdenticonFormat::Svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(matches!(
+            make_identicon_fmt(&multisigner, 72, IdenticonFormat::WebP),
+            Err(Error::UnsupportedIdenticonFormat)
+        ));
+    }
+
+    #[test]
+    fn pair_identicon() {
+        let left = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let right = MultiSigner::Sr25519(sr25519::Public::from_raw([2; 32]));
+        let pair = RgbaImage::from_png(&make_pair_identicon(&left, &right, 72).unwrap()).unwrap();
+        assert_eq!(pair.width, 2 * 72 + PAIR_IDENTICON_GAP as u32);
+        assert_eq!(pair.height, 72);
+        // gap is transparent
+        let gap_offset = pair.offset(72 + PAIR_IDENTICON_GAP as u32 / 2, 36);
+        assert_eq!(pair.pixels[gap_offset + 3], 0);
+    }
+
+    #[test]
+    fn gradient_is_stable() {
+        let multisigner = alice_sr25519();
+        let (start, end) = identicon_gradient(&multisigner);
+        assert_eq!(identicon_gradient(&multisigner), (start, end));
+        assert_ne!(start, end);
+        let other = MultiSigner::Sr25519(sr25519::Public::from_raw([0; 32]));
+        assert_ne!(identicon_gradient(&other), (start, end));
+    }
+
+    #[test]
+    fn stored_identicon_check() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let mut stored = make_identicon_from_multisigner(&multisigner);
+        assert!(identicon_matches(&multisigner, 72, &stored));
+        let last = stored.len() - 1;
+        stored[last] ^= 1;
+        assert!(!identicon_matches(&multisigner, 72, &stored));
+    }
+
+    #[test]
+    fn circular_identicon() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let image = RgbaImage::from_png(&make_identicon_circular(&multisigner, 72)).unwrap();
+        let last = image.width - 1;
+        for (x, y) in [(0, 0), (last, 0), (0, last), (last, last)] {
+            assert_eq!(image.pixels[image.offset(x, y) + 3], 0);
+        }
+        let center = image.offset(image.width / 2, image.height / 2);
+        assert_ne!(image.pixels[center + 3], 0);
+    }
+
+    #[test]
+    fn checked_meta_pic() {
+        let meta_hash = [7; 32];
+        assert_eq!(pic_meta_checked(&meta_hash).unwrap(), pic_meta(&meta_hash));
+        assert!(matches!(
+            pic_meta_checked(&meta_hash[..16]),
+            Err(Error::WrongHashLength {
+                expected: 32,
+                got: 16
+            })
+        ));
+    }
+
+    #[test]
+    fn flagged_identicon() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let flagged = make_identicon_flagged(&multisigner, 72).unwrap();
+        let plain = make_identicon_sized(&identicon_seed(&multisigner), 72).unwrap();
+        assert_ne!(flagged, plain);
+
+        let image = RgbaImage::from_png(&flagged).unwrap();
+        let plain = RgbaImage::from_png(&plain).unwrap();
+        assert_eq!((image.width, image.height), (plain.width, plain.height));
+        let corner = image.offset(image.width - 1, 0);
+        assert_eq!(image.pixels[corner..corner + 4], FLAG_MARKER_COLOR);
+        let center = image.offset(image.width / 2, image.height / 2);
+        assert_eq!(
+            image.pixels[center..center + 4],
+            plain.pixels[center..center + 4]
+        );
+    }
+
+    #[test]
+    fn high_contrast_identicon() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let image = RgbaImage::from_png(&make_identicon_high_contrast(&multisigner, 72)).unwrap();
+        let mut colors: Vec<&[u8]> = image.pixels.chunks(4).collect();
+        colors.sort();
+        colors.dedup();
+        assert_eq!(colors, vec![&[0, 0, 0, 255][..], &[255, 255, 255, 255][..]]);
+    }
+
+    #[test]
+    fn high_contrast_keeps_light_dots() {
+        // dots with high lightness and saturation, e.g. light yellow, are
+        // almost as bright as the background, check that some keys have them
+        let mut light_dots_seen = false;
+        for byte in 0..=u8::MAX {
+            let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([byte; 32]));
+            let normal = RgbaImage::from_png(
+                &make_identicon_sized(&identicon_seed(&multisigner), 72).unwrap(),
+            )
+            .unwrap();
+            let high_contrast =
+                RgbaImage::from_png(&make_identicon_high_contrast(&multisigner, 72)).unwrap();
+            assert_eq!(
+                (normal.width, normal.height),
+                (high_contrast.width, high_contrast.height)
+            );
+            for (pixel, monochrome) in normal.pixels.chunks(4).zip(high_contrast.pixels.chunks(4)) {
+                let is_dot = pixel[3] == 0xff && pixel[..3] != IDENTICON_BACKGROUND;
+                assert_eq!(monochrome[0] == 0, is_dot, "key [{}; 32]", byte);
+                let luma =
+                    (299 * pixel[0] as u32 + 587 * pixel[1] as u32 + 114 * pixel[2] as u32) / 1000;
+                if is_dot && luma >= 0xe0 {
+                    light_dots_seen = true;
+                }
+            }
+        }
+        assert!(light_dots_seen);
+    }
+
+    #[test]
+    fn png_dimensions() {
+        let multisigner = MultiSigner::Sr25519(sr25519::Public::from_raw([1; 32]));
+        let png = make_identicon_from_multisigner(&multisigner);
+        let (width, height) = identicon_dimensions(&png).unwrap();
+        assert_eq!((width, height), (72, 72));
+        let image = RgbaImage::from_png(&png).unwrap();
+        assert_eq!((width, height), (image.width, image.height));
+
+        assert!(matches!(
+            identicon_dimensions(&png[..20]),
+            Err(Error::PngDecoding(_))
+        ));
+        assert!(matches!(
+            identicon_dimensions(&[0; 32]),
+            Err(Error::PngDecoding(_))
+        ));
+    }
+
+    #[test]
+    fn account_card() {
+        let alice = alice_sr25519();
+        let card = make_account_card(&alice, &Encryption::Sr25519, Some(0), 72).unwrap();
+        assert_eq!(card[..8], PNG_SIGNATURE);
+        let (width, height) = identicon_dimensions(&card).unwrap();
+        assert!(width >= 72);
+        assert!(height > 72);
+
+        let image = RgbaImage::from_png(&card).unwrap();
+        assert_eq!((image.width, image.height), (width, height));
+        // some text pixels are drawn below the identicon
+        assert!((74..height).any(|y| (0..width).any(|x| {
+            let offset = image.offset(x, y);
+            image.pixels[offset..offset + 4] == [0, 0, 0, 0xff]
+        })));
+
+        assert_eq!(CARD_FONT_SYMBOLS.len(), CARD_FONT.len());
+        assert!(matches!(
+            make_account_card(&alice, &Encryption::Ecdsa, None, 72),
+            Err(Error::MultiSignerEncryptionMismatch {
+                expected: Encryption::Ecdsa,
+                got: Encryption::Sr25519,
+            })
+        ));
+    }
+}
@@ -25,20 +25,37 @@ pub mod error_active;
 #[cfg(feature = "signer")]
 pub mod error_signer;
 
+pub mod ethereum;
+
 pub mod helpers;
 
 pub mod history;
 
+pub mod identicon;
+
 pub mod keyring;
 
 pub mod metadata;
 
 pub mod network_specs;
 
+pub mod qr;
+
 pub mod qr_transfers;
 
+pub mod reader;
+
+#[cfg(feature = "signer")]
+pub mod redacted;
+
+pub mod ss58;
+
 pub mod types;
 
 pub mod users;
 
 pub mod navigation;
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod test_values;
@@ -0,0 +1,237 @@
+//! Multi-frame QR code helpers
+//!
+//! Payloads too large for a single QR code are split into frames with legacy
+//! multi-frame header, and reassembled from the scanned frames.
+
+use crate::error::{Error, Result};
+
+/// Length of the header prepended to each frame by [`chunk_for_qr`]
+///
+/// Header is the same as for legacy multi-frame QR codes: `0x00` prelude,
+/// followed by the total number of frames and the frame index, both as
+/// big endian `u16`. Zero prelude is what tells legacy multi-frame QR codes
+/// apart from fountain QR codes, that have the first bit set, and from
+/// single-frame payloads, that start with `0x53`.
+pub const QR_FRAME_HEADER_LEN: usize = 5;
+
+/// QR code error correction level
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum QrEcc {
+    /// Recovers about 7% of data
+    Low,
+
+    /// Recovers about 15% of data
+    Medium,
+
+    /// Recovers about 25% of data
+    Quartile,
+
+    /// Recovers about 30% of data
+    High,
+}
+
+/// Byte mode data capacity of QR code versions 1 to 40, for error correction
+/// levels in [`QrEcc`] order
+const QR_BYTE_CAPACITY: [[u16; 40]; 4] = [
+    [
+        17, 32, 53, 78, 106, 134, 154, 192, 230, 271, 321, 367, 425, 458, 520, 586, 644, 718, 792,
+        858, 929, 1003, 1091, 1171, 1273, 1367, 1465, 1528, 1628, 1732, 1840, 1952, 2068, 2188,
+        2303, 2431, 2563, 2699, 2809, 2953,
+    ],
+    [
+        14, 26, 42, 62, 84, 106, 122, 152, 180, 213, 251, 287, 331, 362, 412, 450, 504, 560, 624,
+        666, 711, 779, 857, 911, 997, 1059, 1125, 1190, 1264, 1370, 1452, 1538, 1628, 1722, 1809,
+        1911, 1989, 2099, 2213, 2331,
+    ],
+    [
+        11, 20, 32, 46, 60, 74, 86, 108, 130, 151, 177, 203, 241, 258, 292, 322, 364, 394, 442,
+        482, 509, 565, 611, 661, 715, 751, 805, 868, 908, 982, 1030, 1112, 1168, 1228, 1283, 1351,
+        1423, 1499, 1579, 1663,
+    ],
+    [
+        7, 14, 24, 34, 44, 58, 64, 84, 98, 119, 137, 155, 177, 194, 220, 250, 280, 310, 338, 382,
+        403, 439, 461, 511, 535, 593, 625, 658, 698, 742, 790, 842, 898, 958, 983, 1051, 1093,
+        1139, 1219, 1273,
+    ],
+];
+
+/// Get the smallest QR code version, from 1 to 40, that fits `payload_len`
+/// bytes in byte mode with given error correction level
+pub fn qr_version_for(payload_len: usize, ecc: QrEcc) -> Result<u8> {
+    QR_BYTE_CAPACITY[ecc as usize]
+        .iter()
+        .position(|capacity| payload_len <= *capacity as usize)
+        .map(|i| i as u8 + 1)
+        .ok_or(Error::PayloadTooLargeForQr { len: payload_len })
+}
+
+/// Split payload into frames for multi-frame QR code
+///
+/// Each produced frame starts with [`QR_FRAME_HEADER_LEN`] bytes of header
+/// containing total number of frames and the frame index, and is at most
+/// `max_frame_bytes` long. All frames except the last one are exactly
+/// `max_frame_bytes` long.
+///
+/// Empty payload produces single frame with header only.
+///
+/// Fails if `max_frame_bytes` leaves no space for the data after the frame
+/// header, or if the payload does not fit into `u16::MAX` frames.
+pub fn chunk_for_qr(payload: &[u8], max_frame_bytes: usize) -> Result<Vec<Vec<u8>>> {
+    if max_frame_bytes <= QR_FRAME_HEADER_LEN {
+        return Err(Error::QrFrameTooSmall { max_frame_bytes });
+    }
+    let chunk_size = max_frame_bytes - QR_FRAME_HEADER_LEN;
+    let chunks: Vec<&[u8]> = if payload.is_empty() {
+        vec![payload]
+    } else {
+        payload.chunks(chunk_size).collect()
+    };
+    let total =
+        u16::try_from(chunks.len()).map_err(|_| Error::TooManyQrFrames { len: payload.len() })?;
+    let frames = chunks
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut frame = Vec::with_capacity(QR_FRAME_HEADER_LEN + chunk.len());
+            // legacy multi-frame prelude
+            frame.push(0);
+            frame.extend_from_slice(&total.to_be_bytes());
+            // index is below total, and total fits into u16
+            frame.extend_from_slice(&(index as u16).to_be_bytes());
+            frame.extend_from_slice(chunk);
+            frame
+        })
+        .collect();
+    Ok(frames)
+}
+
+/// Reassemble payload from the frames produced by [`chunk_for_qr`]
+///
+/// Frames could go in any order, each frame must be present exactly once.
+pub fn reassemble_qr_frames(frames: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let first = frames.first().ok_or(Error::MissingFrame { index: 0 })?;
+    let (total, _) = qr_frame_header(first)?;
+    let mut collected: Vec<Option<&[u8]>> = vec![None; total as usize];
+    for frame in frames.iter() {
+        let (frame_total, index) = qr_frame_header(frame)?;
+        if frame_total != total {
+            return Err(Error::FrameCountMismatch);
+        }
+        let slot = collected
+            .get_mut(index as usize)
+            .ok_or(Error::FrameCountMismatch)?;
+        if slot.is_some() {
+            return Err(Error::FrameCountMismatch);
+        }
+        *slot = Some(&frame[QR_FRAME_HEADER_LEN..]);
+    }
+    if collected.is_empty() {
+        return Err(Error::FrameCountMismatch);
+    }
+    let mut out: Vec<u8> = Vec::new();
+    for (index, slot) in collected.iter().enumerate() {
+        match slot {
+            Some(data) => out.extend_from_slice(data),
+            None => {
+                return Err(Error::MissingFrame {
+                    index: index as u16,
+                })
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Get total number of frames and frame index from multi-frame QR code frame
+fn qr_frame_header(frame: &[u8]) -> Result<(u16, u16)> {
+    match frame.get(..QR_FRAME_HEADER_LEN) {
+        Some(&[0, total_hi, total_lo, index_hi, index_lo]) => Ok((
+            u16::from_be_bytes([total_hi, total_lo]),
+            u16::from_be_bytes([index_hi, index_lo]),
+        )),
+        _ => Err(Error::InvalidQrFrame),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_for_qr_even_split() {
+        let payload: Vec<u8> = (0..10).collect();
+        let frames = chunk_for_qr(&payload, 10).unwrap();
+        assert_eq!(
+            frames,
+            vec![
+                vec![0, 0, 2, 0, 0, 0, 1, 2, 3, 4],
+                vec![0, 0, 2, 0, 1, 5, 6, 7, 8, 9],
+            ]
+        );
+    }
+
+    #[test]
+    fn chunk_for_qr_partial_last_frame() {
+        let payload: Vec<u8> = (0..12).collect();
+        let frames = chunk_for_qr(&payload, 10).unwrap();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], vec![0, 0, 3, 0, 0, 0, 1, 2, 3, 4]);
+        assert_eq!(frames[1], vec![0, 0, 3, 0, 1, 5, 6, 7, 8, 9]);
+        assert_eq!(frames[2], vec![0, 0, 3, 0, 2, 10, 11]);
+    }
+
+    #[test]
+    fn chunk_for_qr_invalid_frame_size() {
+        let payload: Vec<u8> = (0..12).collect();
+        for max_frame_bytes in 0..=QR_FRAME_HEADER_LEN {
+            assert!(matches!(
+                chunk_for_qr(&payload, max_frame_bytes),
+                Err(Error::QrFrameTooSmall { max_frame_bytes: a }) if a == max_frame_bytes
+            ));
+        }
+        let payload = vec![0; u16::MAX as usize + 1];
+        assert!(matches!(
+            chunk_for_qr(&payload, QR_FRAME_HEADER_LEN + 1),
+            Err(Error::TooManyQrFrames { len }) if len == payload.len()
+        ));
+    }
+
+    #[test]
+    fn reassemble_qr_frames_in_order() {
+        let payload: Vec<u8> = (0..12).collect();
+        let frames = chunk_for_qr(&payload, 10).unwrap();
+        assert_eq!(reassemble_qr_frames(&frames).unwrap(), payload);
+    }
+
+    #[test]
+    fn reassemble_qr_frames_shuffled() {
+        let payload: Vec<u8> = (0..12).collect();
+        let mut frames = chunk_for_qr(&payload, 10).unwrap();
+        frames.swap(0, 2);
+        assert_eq!(reassemble_qr_frames(&frames).unwrap(), payload);
+    }
+
+    #[test]
+    fn reassemble_qr_frames_missing_frame() {
+        let payload: Vec<u8> = (0..12).collect();
+        let mut frames = chunk_for_qr(&payload, 10).unwrap();
+        frames.remove(1);
+        match reassemble_qr_frames(&frames) {
+            Err(Error::MissingFrame { index: 1 }) => (),
+            a => panic!("Expected missing frame 1, got {:?}", a),
+        }
+    }
+
+    #[test]
+    fn qr_versions() {
+        assert_eq!(qr_version_for(10, QrEcc::Low).unwrap(), 1);
+        assert_eq!(qr_version_for(10, QrEcc::High).unwrap(), 2);
+        assert_eq!(qr_version_for(2953, QrEcc::Low).unwrap(), 40);
+        assert!(matches!(
+            qr_version_for(2954, QrEcc::Low),
+            Err(Error::PayloadTooLargeForQr { len: 2954 })
+        ));
+        assert!(qr_version_for(2000, QrEcc::High).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! Bounds-checked reads of fixed byte layouts
+
+use crate::error::{Error, Result};
+
+/// Reader for fixed layouts in byte slices
+///
+/// All reads are bounds-checked, and fail without consuming anything if
+/// there are not enough bytes left.
+#[derive(Clone, Debug)]
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// New reader, positioned at the start of `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Read next `n` bytes
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if n > self.data.len() {
+            return Err(Error::UnexpectedEndOfData {
+                requested: n,
+                left: self.data.len(),
+            });
+        }
+        let (bytes, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(bytes)
+    }
+
+    /// Read next `N` bytes as array
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0; N];
+        out.copy_from_slice(self.read_bytes(N)?);
+        Ok(out)
+    }
+
+    /// Check that all bytes are read
+    pub fn expect_end(&self) -> Result<()> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::TrailingBytes {
+                left: self.data.len(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_reader() {
+        let data = [[1, 0].as_slice(), &[7; 32], &[0xff]].concat();
+        let mut reader = ByteReader::new(&data);
+        assert_eq!(reader.read_array::<2>().unwrap(), [1, 0]);
+        assert_eq!(reader.read_array::<32>().unwrap(), [7; 32]);
+        assert!(matches!(
+            reader.expect_end(),
+            Err(Error::TrailingBytes { left: 1 })
+        ));
+        assert!(matches!(
+            reader.read_array::<2>(),
+            Err(Error::UnexpectedEndOfData {
+                requested: 2,
+                left: 1
+            })
+        ));
+        assert_eq!(reader.read_bytes(1).unwrap(), &[0xff]);
+        assert!(reader.expect_end().is_ok());
+        assert!(reader.read_bytes(1).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+//! Wrapper for secrets that must not be printed
+
+use std::fmt;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Wrapper for values containing secrets
+///
+/// [`Debug`] and [`Display`](fmt::Display) for `Redacted` never show the
+/// wrapped value, so that the secrets could not get into logs or error
+/// messages by accident. Wrapped value is accessible only explicitly, through
+/// [`expose`](Redacted::expose) and [`expose_mut`](Redacted::expose_mut).
+///
+/// Wrapped value is zeroized on drop.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Redacted<T: Zeroize>(T);
+
+impl<T: Zeroize> Redacted<T> {
+    /// Wrap the secret value
+    pub fn new(secret: T) -> Self {
+        Self(secret)
+    }
+
+    /// Get reference to the secret value
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Get mutable reference to the secret value
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<T: Zeroize> fmt::Display for Redacted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<T: Zeroize> Zeroize for Redacted<T> {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T: Zeroize> Drop for Redacted<T> {
+    fn drop(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for Redacted<T> {}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacted_formatting_hides_secret() {
+        let secret = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+        let redacted = Redacted::new(secret.to_string());
+        for formatted in [
+            format!("{:?}", redacted),
+            format!("{:#?}", redacted),
+            format!("{}", redacted),
+            format!("{:?}", Some(&redacted)),
+        ] {
+            assert!(
+                !formatted.contains("bottom"),
+                "Secret leaked: {}",
+                formatted
+            );
+        }
+        assert_eq!(redacted.expose(), secret);
+    }
+
+    #[test]
+    fn redacted_bytes_hidden() {
+        let redacted = Redacted::new(vec![0xde_u8, 0xad, 0xbe, 0xef]);
+        assert_eq!(format!("{:?}", redacted), "<redacted>");
+        assert_eq!(format!("{}", redacted), "<redacted>");
+    }
+
+    #[test]
+    fn redacted_zeroize() {
+        let mut redacted = Redacted::new(String::from("bottom drive obey lake"));
+        redacted.zeroize();
+        assert!(redacted.expose().is_empty());
+    }
+}
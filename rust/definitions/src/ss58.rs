@@ -0,0 +1,895 @@
+//! SS58 address helpers
+//!
+//! Base58 codec, SS58 address encoding and decoding with prefix and checksum
+//! checks, and matching of addresses against known networks.
+
+use sp_core::crypto::{AccountId32, PublicError, Ss58AddressFormat, Ss58Codec};
+use sp_core::hashing::blake2_512;
+use sp_core::{blake2_256, ecdsa, H160, H256};
+use sp_runtime::traits::IdentifyAccount;
+use sp_runtime::MultiSigner;
+
+use crate::crypto::Encryption;
+use crate::error::{Error, Result};
+use crate::ethereum::{checksum_eth_address, normalize_eth_address};
+#[cfg(feature = "signer")]
+use crate::helpers::get_multisigner;
+use crate::helpers::{
+    multisigner_to_public, print_multisigner_as_base58, public_from_bytes_checked, Hex0x,
+};
+
+/// Print on-chain account of
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// in base58 format
+///
+/// Chain identifies accounts by 32-byte
+/// [`AccountId32`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/struct.AccountId32.html).
+/// For `Ed25519` and `Sr25519` it is the public key itself, and the address
+/// is the same as in [`print_multisigner_as_base58`]. For `Ecdsa` account id
+/// is `blake2_256` hash of the 33-byte public key, and the address differs
+/// from the one printed for the public key.
+pub fn print_onchain_account_ss58(m: &MultiSigner, optional_prefix: Option<u16>) -> String {
+    account_id_to_ss58(&m.clone().into_account(), optional_prefix)
+}
+
+/// Check if two base58 addresses belong to the same account
+///
+/// Addresses are compared by their public keys, base58 prefixes are ignored.
+pub fn same_account(address1: &str, address2: &str) -> Result<bool> {
+    let (_, public1) = decode_ss58(address1)?;
+    let (_, public2) = decode_ss58(address2)?;
+    Ok(public1 == public2)
+}
+
+/// Check that base58 address of
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// with given prefix decodes back into the same prefix and public key
+pub fn ss58_roundtrips(multisigner: &MultiSigner, prefix: u16) -> bool {
+    match decode_ss58(&print_multisigner_as_base58(multisigner, Some(prefix))) {
+        Ok((decoded_prefix, public)) => {
+            decoded_prefix == prefix && public == multisigner_to_public(multisigner)
+        }
+        Err(_) => false,
+    }
+}
+
+/// Select base58 prefixes, for which [`ss58_roundtrips`] holds
+pub fn valid_prefixes_for(multisigner: &MultiSigner, candidates: &[u16]) -> Vec<u16> {
+    candidates
+        .iter()
+        .copied()
+        .filter(|prefix| ss58_roundtrips(multisigner, *prefix))
+        .collect()
+}
+
+/// Print [`AccountId32`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/struct.AccountId32.html)
+/// in base58 format
+///
+/// Network-specific base58 prefix is used if provided as `Some(value)`,
+/// otherwise the default one is used, same as in [`print_multisigner_as_base58`].
+pub fn account_id_to_ss58(account: &AccountId32, optional_prefix: Option<u16>) -> String {
+    match optional_prefix {
+        Some(base58prefix) => {
+            account.to_ss58check_with_version(Ss58AddressFormat::custom(base58prefix))
+        }
+        None => account.to_ss58check(),
+    }
+}
+
+/// Get [`AccountId32`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/struct.AccountId32.html)
+/// from base58 address with any prefix
+///
+/// Only 32 byte long public keys are accepted, i.e. `Ed25519` and `Sr25519`
+/// ones. Addresses made for `Ecdsa` 33 byte public keys result in error.
+pub fn account_id_from_ss58(address: &str) -> Result<AccountId32> {
+    let (account, _) =
+        AccountId32::from_ss58check_with_version(address).map_err(Error::Base58Decoding)?;
+    Ok(account)
+}
+
+/// Get base58 prefix from its encoded form, as it is found in the beginning
+/// of decoded base58 address
+///
+/// Prefixes `0..=63` are encoded as a single byte. Prefixes `64..=16383` are
+/// encoded as two bytes, `01pppppp pppppppp`: first byte contains bits `2..8`
+/// of the prefix, second byte contains bits `0..2` of the prefix in its upper
+/// two bits, and bits `8..14` of the prefix in its lower six bits.
+pub fn prefix_from_bytes(bytes: &[u8]) -> Result<u16> {
+    match bytes {
+        [first] if *first < 64 => Ok(*first as u16),
+        [first, second] if (64..128).contains(first) => {
+            let lower = (first << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            Ok((lower as u16) | ((upper as u16) << 8))
+        }
+        _ => Err(Error::InvalidBase58PrefixBytes),
+    }
+}
+
+/// Encode base58 prefix as it is found in the beginning of decoded base58
+/// address
+///
+/// See [`prefix_from_bytes`] for encoding details. Prefix is limited to 14
+/// bits, upper two bits are ignored, same as in
+/// [`Ss58Codec`](https://docs.rs/sp-core/6.0.0/sp_core/crypto/trait.Ss58Codec.html).
+pub fn prefix_to_bytes(prefix: u16) -> Vec<u8> {
+    let prefix = prefix & 0b0011_1111_1111_1111;
+    if prefix < 64 {
+        vec![prefix as u8]
+    } else {
+        let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+        let second = ((prefix >> 8) as u8) | ((prefix & 0b0000_0000_0000_0011) as u8) << 6;
+        vec![first | 0b0100_0000, second]
+    }
+}
+
+/// Highest base58 prefix that fits into two-byte encoding
+pub const MAX_BASE58_PREFIX: u16 = 16383;
+
+/// Check that scanned public key and base58 prefix could be used together
+///
+/// Public key length must match the encryption. Base58 prefix must be within
+/// the two-byte prefix range, and must not be one of the reserved prefixes
+/// `46` and `47`.
+pub fn validate_scan(public: &[u8], encryption: &Encryption, prefix: u16) -> Result<()> {
+    public_from_bytes_checked(public, encryption)?;
+    if prefix > MAX_BASE58_PREFIX || prefix == 46 || prefix == 47 {
+        return Err(Error::InvalidBase58Prefix(prefix));
+    }
+    Ok(())
+}
+
+/// Decode base58 address into base58 prefix and public key
+///
+/// Public keys of 32 bytes (`Ed25519` and `Sr25519`) and of 33 bytes
+/// (`Ecdsa`) are supported.
+pub fn decode_ss58(address: &str) -> Result<(u16, Vec<u8>)> {
+    match AccountId32::from_ss58check_with_version(address) {
+        Ok((account, format)) => Ok((u16::from(format), <[u8; 32]>::from(account).to_vec())),
+        Err(PublicError::BadLength) => {
+            let (public, format) = ecdsa::Public::from_ss58check_with_version(address)
+                .map_err(Error::Base58Decoding)?;
+            Ok((u16::from(format), public.0.to_vec()))
+        }
+        Err(e) => Err(Error::Base58Decoding(e)),
+    }
+}
+
+/// Maximum length of base58 string that could encode `bytes_len` bytes
+///
+/// Each base58 symbol carries `log2(58) ≈ 5.858` bits.
+fn base58_max_len(bytes_len: usize) -> usize {
+    bytes_len.saturating_mul(136_566) / 100_000 + 1
+}
+
+/// Length of base58 checksum in base58 address
+const SS58_CHECKSUM_LEN: usize = 2;
+
+/// Decode base58 string into bytes
+fn base58_decode(input: &str) -> Result<Vec<u8>> {
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    // big number, little endian
+    let mut decoded: Vec<u8> = Vec::new();
+    for symbol in input.bytes() {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|a| *a == symbol)
+            .ok_or(Error::Base58Decoding(PublicError::BadBase58))? as u32;
+        for byte in decoded.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            decoded.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // each leading `1` is a leading zero byte
+    let zeroes = input.bytes().take_while(|symbol| *symbol == b'1').count();
+    decoded.resize(decoded.len() + zeroes, 0);
+    decoded.reverse();
+    Ok(decoded)
+}
+
+/// Encode bytes into base58 string
+fn base58_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    // big number in base 58, little endian
+    let mut digits: Vec<u8> = Vec::new();
+    for byte in input {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    // each leading zero byte is a leading `1`
+    let zeroes = input.iter().take_while(|byte| **byte == 0).count();
+    digits.resize(digits.len() + zeroes, 0);
+    digits
+        .iter()
+        .rev()
+        .map(|digit| ALPHABET[*digit as usize] as char)
+        .collect()
+}
+
+/// Context prefix for base58 address checksum hash
+const SS58_HASH_PREFIX: &[u8] = b"SS58PRE";
+
+/// Base58 address checksum hash of prefix and payload
+fn ss58_hash(data: &[u8]) -> [u8; 64] {
+    blake2_512(&[SS58_HASH_PREFIX, data].concat())
+}
+
+/// Print account index in base58 address format with given prefix, or with
+/// [default](https://docs.rs/sp-core/6.0.0/sp_core/crypto/trait.Ss58Codec.html#method.to_ss58check)
+/// prefix `42` if `None`
+///
+/// Index is encoded as the shortest of 1, 2 or 4 little endian bytes that
+/// fits it, with one byte of checksum.
+pub fn account_index_to_ss58(index: u32, optional_prefix: Option<u16>) -> String {
+    let mut data = prefix_to_bytes(optional_prefix.unwrap_or(42));
+    let index_bytes = index.to_le_bytes();
+    let index_len = match index {
+        0..=0xff => 1,
+        0x100..=0xffff => 2,
+        _ => 4,
+    };
+    data.extend_from_slice(&index_bytes[..index_len]);
+    let checksum = ss58_hash(&data)[0];
+    data.push(checksum);
+    base58_encode(&data)
+}
+
+/// Get account index from base58 address in account index format, with any
+/// prefix
+///
+/// Index could be encoded in 1, 2, 4 or 8 bytes, indices above `u32::MAX`
+/// are rejected. Addresses longer than any valid account index address are
+/// rejected before decoding.
+pub fn parse_account_index(address: &str) -> Result<u32> {
+    // two-byte prefix, eight-byte index, one-byte checksum
+    if address.len() > base58_max_len(2 + 8 + 1) {
+        return Err(Error::InvalidAccountIndex);
+    }
+    let decoded = base58_decode(address)?;
+    let prefix_len = match decoded.first() {
+        Some(0..=63) => 1,
+        Some(64..=127) => 2,
+        _ => return Err(Error::InvalidAccountIndex),
+    };
+    // index and one byte of checksum
+    let index_len = decoded.len().saturating_sub(prefix_len + 1);
+    if ![1, 2, 4, 8].contains(&index_len) || decoded.len() != prefix_len + index_len + 1 {
+        return Err(Error::InvalidAccountIndex);
+    }
+    prefix_from_bytes(&decoded[..prefix_len])?;
+    let (data, checksum) = decoded.split_at(decoded.len() - 1);
+    if ss58_hash(data)[0] != checksum[0] {
+        return Err(Error::Base58Decoding(PublicError::InvalidChecksum));
+    }
+    let mut index_bytes = [0; 8];
+    index_bytes[..index_len].copy_from_slice(&data[prefix_len..]);
+    u32::try_from(u64::from_le_bytes(index_bytes)).map_err(|_| Error::InvalidAccountIndex)
+}
+
+/// Get the length of public key in base58 address, without checking the
+/// checksum
+///
+/// Addresses longer than any valid base58 address are rejected before
+/// decoding.
+pub fn ss58_expected_payload_len(address: &str) -> Result<usize> {
+    // two-byte prefix, `Ecdsa` public key, checksum
+    if address.len() > base58_max_len(2 + 33 + SS58_CHECKSUM_LEN) {
+        return Err(Error::Base58AddressTooLong);
+    }
+    let decoded = base58_decode(address)?;
+    let prefix_len = match decoded.first() {
+        Some(0..=63) => 1,
+        Some(64..=127) => 2,
+        _ => return Err(Error::InvalidBase58PrefixBytes),
+    };
+    decoded
+        .len()
+        .checked_sub(prefix_len + SS58_CHECKSUM_LEN)
+        .ok_or(Error::Base58Decoding(PublicError::BadLength))
+}
+
+/// Decode base58 address with [`decode_ss58`], refusing the addresses that
+/// could contain public key longer than `max_payload`
+///
+/// Address length is checked before decoding.
+pub fn decode_ss58_bounded(address: &str, max_payload: usize) -> Result<(u16, Vec<u8>)> {
+    if address.len() > base58_max_len(max_payload.saturating_add(2 + SS58_CHECKSUM_LEN)) {
+        return Err(Error::Base58AddressTooLong);
+    }
+    let (prefix, public) = decode_ss58(address)?;
+    if public.len() > max_payload {
+        return Err(Error::Base58AddressTooLong);
+    }
+    Ok((prefix, public))
+}
+
+/// Guess [`Encryption`](crate::crypto::Encryption) of base58 address from
+/// its public key length
+///
+/// 33-byte public key is `Ecdsa`. `Ed25519` and `Sr25519` public keys are both
+/// 32 bytes long and could not be told apart, for them the more common
+/// `Sr25519` is returned.
+pub fn infer_encryption_from_ss58(address: &str) -> Result<Encryption> {
+    let (_, public) = decode_ss58(address)?;
+    if public.len() == Encryption::Ecdsa.public_key_len() {
+        Ok(Encryption::Ecdsa)
+    } else {
+        Ok(Encryption::Sr25519)
+    }
+}
+
+/// Get all [`Encryption`](crate::crypto::Encryption)s with public key of
+/// given length, e.g. to let user pick one for decoded base58 address
+///
+/// 32 bytes are `Ed25519` and `Sr25519`, 33 bytes are `Ecdsa`. Other lengths
+/// have no encryptions.
+pub fn possible_encryptions_for_payload_len(len: usize) -> Vec<Encryption> {
+    [Encryption::Ed25519, Encryption::Sr25519, Encryption::Ecdsa]
+        .into_iter()
+        .filter(|encryption| encryption.public_key_len() == len)
+        .collect()
+}
+
+/// Genesis hash of Polkadot
+const POLKADOT_GENESIS_HASH: H256 = H256([
+    0x91, 0xb1, 0x71, 0xbb, 0x15, 0x8e, 0x2d, 0x38, 0x48, 0xfa, 0x23, 0xa9, 0xf1, 0xc2, 0x51, 0x82,
+    0xfb, 0x8e, 0x20, 0x31, 0x3b, 0x2c, 0x1e, 0xb4, 0x92, 0x19, 0xda, 0x7a, 0x70, 0xce, 0x90, 0xc3,
+]);
+
+/// Genesis hash of Kusama
+const KUSAMA_GENESIS_HASH: H256 = H256([
+    0xb0, 0xa8, 0xd4, 0x93, 0x28, 0x5c, 0x2d, 0xf7, 0x32, 0x90, 0xdf, 0xb7, 0xe6, 0x1f, 0x87, 0x0f,
+    0x17, 0xb4, 0x18, 0x01, 0x19, 0x7a, 0x14, 0x9c, 0xa9, 0x36, 0x54, 0x49, 0x9e, 0xa3, 0xda, 0xfe,
+]);
+
+/// Genesis hash of Westend
+const WESTEND_GENESIS_HASH: H256 = H256([
+    0xe1, 0x43, 0xf2, 0x38, 0x03, 0xac, 0x50, 0xe8, 0xf6, 0xf8, 0xe6, 0x26, 0x95, 0xd1, 0xce, 0x9e,
+    0x4e, 0x1d, 0x68, 0xaa, 0x36, 0xc1, 0xcd, 0x2c, 0xfd, 0x15, 0x34, 0x02, 0x13, 0xf3, 0x42, 0x3e,
+]);
+
+/// Base58 prefixes and sets of genesis hashes of known networks
+///
+/// Genesis hashes in each set belong to the same network, e.g. if the
+/// network was restarted from a new genesis. First genesis hash in each set is
+/// the canonical one.
+const GENESIS_ALIASES: [(u16, &[H256]); 3] = [
+    (0, &[POLKADOT_GENESIS_HASH]),
+    (2, &[KUSAMA_GENESIS_HASH]),
+    (42, &[WESTEND_GENESIS_HASH]),
+];
+
+/// Get all known genesis hashes of the network with given genesis hash,
+/// including the given one
+///
+/// Empty set is returned for unknown networks.
+pub fn genesis_aliases(genesis_hash: &H256) -> &'static [H256] {
+    genesis_aliases_in(&GENESIS_ALIASES, genesis_hash)
+}
+
+/// Get base58 prefix of default network by its genesis hash
+///
+/// Only the default networks, Polkadot, Kusama and Westend, are known. Any
+/// of the network [`genesis_aliases`] could be used.
+pub fn prefix_for_genesis(genesis_hash: &H256) -> Option<u16> {
+    prefix_for_genesis_in(&GENESIS_ALIASES, genesis_hash)
+}
+
+/// Check if base58 address prefix matches the network with given genesis hash
+///
+/// Network base58 prefix is found with [`prefix_for_genesis`], so any of
+/// the network [`genesis_aliases`] could be used. Unknown genesis hash
+/// results in an error.
+pub fn address_matches_network(address: &str, genesis_hash: &H256) -> Result<bool> {
+    address_matches_network_in(&GENESIS_ALIASES, address, genesis_hash)
+}
+
+/// [`genesis_aliases`] with given table of known networks
+fn genesis_aliases_in(networks: &[(u16, &'static [H256])], genesis_hash: &H256) -> &'static [H256] {
+    networks
+        .iter()
+        .find(|(_, aliases)| aliases.contains(genesis_hash))
+        .map(|(_, aliases)| *aliases)
+        .unwrap_or(&[])
+}
+
+/// [`prefix_for_genesis`] with given table of known networks
+fn prefix_for_genesis_in(networks: &[(u16, &[H256])], genesis_hash: &H256) -> Option<u16> {
+    networks
+        .iter()
+        .find(|(_, aliases)| aliases.contains(genesis_hash))
+        .map(|(prefix, _)| *prefix)
+}
+
+/// [`address_matches_network`] with given table of known networks
+fn address_matches_network_in(
+    networks: &[(u16, &[H256])],
+    address: &str,
+    genesis_hash: &H256,
+) -> Result<bool> {
+    let network_prefix = prefix_for_genesis_in(networks, genesis_hash)
+        .ok_or(Error::UnknownNetworkGenesisHash(*genesis_hash))?;
+    let (address_prefix, _) = decode_ss58(address)?;
+    Ok(address_prefix == network_prefix)
+}
+
+/// Check if base58 prefix of the signer matches the network of the payload
+/// with given genesis hash
+///
+/// Network base58 prefix is found with [`prefix_for_genesis`]. Unknown
+/// genesis hash never matches.
+pub fn signer_network_matches_payload(signer_ss58_prefix: u16, payload_genesis: &H256) -> bool {
+    prefix_for_genesis(payload_genesis) == Some(signer_ss58_prefix)
+}
+
+/// Decode a list of base58 addresses with [`decode_ss58`], one result per
+/// address
+///
+/// Lines are trimmed, empty lines are skipped and produce no result.
+pub fn decode_ss58_list(lines: &[&str]) -> Vec<Result<(u16, Vec<u8>)>> {
+    lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(decode_ss58)
+        .collect()
+}
+
+/// Check if all base58 addresses have the same prefix
+///
+/// Empty set is considered coherent. Any address that could not be decoded
+/// results in error, Ethereum addresses result in
+/// [`Error::UnexpectedEthereumAddress`].
+pub fn all_same_network(addresses: &[&str]) -> Result<bool> {
+    let mut first_prefix = None;
+    let mut same = true;
+    for address in addresses {
+        if address.starts_with(Hex0x::PREFIX) {
+            return Err(Error::UnexpectedEthereumAddress);
+        }
+        let (prefix, _) = decode_ss58(address)?;
+        match first_prefix {
+            None => first_prefix = Some(prefix),
+            Some(first) => same &= first == prefix,
+        }
+    }
+    Ok(same)
+}
+
+/// Decode base58 address into
+/// [`MultiSigner`](https://docs.rs/sp-runtime/6.0.0/sp_runtime/enum.MultiSigner.html)
+/// of expected [`Encryption`](crate::crypto::Encryption)
+///
+/// Public key length must match the expected encryption.
+#[cfg(feature = "signer")]
+pub fn decode_ss58_expecting(address: &str, expected: &Encryption) -> Result<MultiSigner> {
+    let (_, public) = decode_ss58(address)?;
+    if public.len() != expected.public_key_len() {
+        return Err(Error::Base58EncryptionMismatch {
+            expected: expected.to_owned(),
+            public_len: public.len(),
+        });
+    }
+    get_multisigner(&public, expected)
+}
+
+/// Replace base58 or Ethereum address with a fake one of the same format,
+/// for screenshots and docs
+///
+/// Fake payload is taken from `blake2_256` hash of the original one, so that
+/// the same address always gets the same replacement. Base58 addresses keep
+/// their prefix, fake `Ecdsa` public keys keep valid compressed key prefix
+/// byte. Ethereum addresses are printed with EIP-55 checksum.
+pub fn anonymize_address(address: &str) -> Result<String> {
+    if address.starts_with(Hex0x::PREFIX) {
+        let hash = blake2_256(normalize_eth_address(address)?.as_bytes());
+        return Ok(checksum_eth_address(&H160::from_slice(&hash[..20])));
+    }
+    let (prefix, public) = decode_ss58(address)?;
+    let hash = blake2_256(&public);
+    let format = Ss58AddressFormat::custom(prefix);
+    if public.len() == 33 {
+        let mut fake = [0; 33];
+        fake[0] = 0x02 | (hash[0] & 1);
+        fake[1..].copy_from_slice(&hash);
+        Ok(ecdsa::Public::from_raw(fake).to_ss58check_with_version(format))
+    } else {
+        Ok(AccountId32::new(hash).to_ss58check_with_version(format))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "test")]
+mod tests {
+    use super::*;
+    use crate::test_values::{alice_ecdsa, alice_sr25519};
+    use hex;
+    use std::str::FromStr;
+
+    #[test]
+    fn account_id_ss58_round_trip() {
+        let account = AccountId32::new(
+            hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+        let address = account_id_to_ss58(&account, Some(0));
+        assert_eq!(address, "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5");
+        assert_eq!(account_id_from_ss58(&address).unwrap(), account);
+        assert_eq!(
+            account_id_to_ss58(&account, None),
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"
+        );
+    }
+
+    #[test]
+    fn account_id_from_ecdsa_ss58() {
+        let result = account_id_from_ss58("1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy");
+        if let Err(Error::Base58Decoding(PublicError::BadLength)) = result {
+        } else {
+            panic!("Expected bad length error, got {:?}", result);
+        }
+    }
+
+    #[test]
+    fn single_byte_prefix() {
+        assert_eq!(prefix_to_bytes(42), vec![42]);
+        assert_eq!(prefix_from_bytes(&[42]).unwrap(), 42);
+    }
+
+    #[test]
+    fn two_byte_prefix() {
+        assert_eq!(prefix_to_bytes(10041), vec![78, 103]);
+        assert_eq!(prefix_from_bytes(&[78, 103]).unwrap(), 10041);
+        assert_eq!(prefix_from_bytes(&prefix_to_bytes(64)).unwrap(), 64);
+        assert_eq!(prefix_from_bytes(&prefix_to_bytes(16383)).unwrap(), 16383);
+    }
+
+    #[test]
+    fn invalid_prefix_bytes() {
+        for bytes in [&[][..], &[64], &[42, 0], &[128, 0]] {
+            assert!(matches!(
+                prefix_from_bytes(bytes),
+                Err(Error::InvalidBase58PrefixBytes)
+            ));
+        }
+    }
+
+    #[test]
+    fn same_account_ignores_prefix() {
+        assert!(same_account(
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"
+        )
+        .unwrap());
+        assert!(!same_account(
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy"
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn decode_ss58_list_per_line() {
+        let decoded = decode_ss58_list(&[
+            " 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "",
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ",
+            "   ",
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy\n",
+        ]);
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(
+            decoded[0].as_ref().unwrap(),
+            &(
+                42,
+                hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                    .unwrap()
+            )
+        );
+        assert!(matches!(
+            decoded[1],
+            Err(Error::Base58Decoding(PublicError::InvalidChecksum))
+        ));
+        assert_eq!(decoded[2].as_ref().unwrap().0, 0);
+    }
+
+    #[test]
+    fn scanned_public_and_prefix() {
+        let public =
+            hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+                .unwrap();
+        assert!(validate_scan(&public, &Encryption::Sr25519, 42).is_ok());
+        assert!(validate_scan(&public, &Encryption::Sr25519, MAX_BASE58_PREFIX).is_ok());
+        assert!(matches!(
+            validate_scan(&public, &Encryption::Ecdsa, 42),
+            Err(Error::WrongPublicKeyLength)
+        ));
+        assert!(matches!(
+            validate_scan(&public, &Encryption::Sr25519, 16384),
+            Err(Error::InvalidBase58Prefix(16384))
+        ));
+        assert!(matches!(
+            validate_scan(&public, &Encryption::Sr25519, 46),
+            Err(Error::InvalidBase58Prefix(46))
+        ));
+    }
+
+    #[test]
+    fn bounded_ss58_decoding() {
+        let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+        let alice_ecdsa = "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy";
+        assert_eq!(ss58_expected_payload_len(alice).unwrap(), 32);
+        assert_eq!(ss58_expected_payload_len(alice_ecdsa).unwrap(), 33);
+        assert_eq!(
+            decode_ss58_bounded(alice, 33).unwrap(),
+            decode_ss58(alice).unwrap()
+        );
+        assert!(matches!(
+            decode_ss58_bounded(alice_ecdsa, 32),
+            Err(Error::Base58AddressTooLong)
+        ));
+
+        let oversized = "5".repeat(10_000);
+        assert!(matches!(
+            ss58_expected_payload_len(&oversized),
+            Err(Error::Base58AddressTooLong)
+        ));
+        assert!(matches!(
+            decode_ss58_bounded(&oversized, 33),
+            Err(Error::Base58AddressTooLong)
+        ));
+        assert!(matches!(
+            ss58_expected_payload_len("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKut0Y"),
+            Err(Error::Base58Decoding(PublicError::BadBase58))
+        ));
+    }
+
+    #[test]
+    fn encryption_from_ss58() {
+        assert_eq!(
+            infer_encryption_from_ss58("1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy").unwrap(),
+            Encryption::Ecdsa
+        );
+        assert_eq!(
+            infer_encryption_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap(),
+            Encryption::Sr25519
+        );
+        assert!(matches!(
+            infer_encryption_from_ss58("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ"),
+            Err(Error::Base58Decoding(_))
+        ));
+    }
+
+    #[test]
+    fn address_network_match() {
+        let polkadot =
+            H256::from_str("91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3")
+                .unwrap();
+        let kusama =
+            H256::from_str("b0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe")
+                .unwrap();
+        let alice_polkadot = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        assert!(address_matches_network(alice_polkadot, &polkadot).unwrap());
+        assert!(!address_matches_network(alice_polkadot, &kusama).unwrap());
+        assert!(matches!(
+            address_matches_network(alice_polkadot, &H256([0; 32])),
+            Err(Error::UnknownNetworkGenesisHash(_))
+        ));
+    }
+
+    #[test]
+    fn ss58_with_expected_encryption() {
+        assert_eq!(
+            decode_ss58_expecting(
+                "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                &Encryption::Sr25519
+            )
+            .unwrap(),
+            alice_sr25519()
+        );
+        assert!(matches!(
+            decode_ss58_expecting(
+                "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy",
+                &Encryption::Sr25519
+            ),
+            Err(Error::Base58EncryptionMismatch {
+                expected: Encryption::Sr25519,
+                public_len: 33
+            })
+        ));
+    }
+
+    #[test]
+    fn prefixes_with_roundtrip() {
+        let multisigner = alice_sr25519();
+        assert_eq!(
+            valid_prefixes_for(&multisigner, &[0, 2, 42, 63, 64, 16383, 16384, u16::MAX]),
+            vec![0, 2, 42, 63, 64, 16383]
+        );
+    }
+
+    #[test]
+    fn anonymized_addresses() {
+        for address in [
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy",
+        ] {
+            let fake = anonymize_address(address).unwrap();
+            assert_ne!(fake, address);
+            assert_eq!(fake, anonymize_address(address).unwrap());
+            let (prefix, public) = decode_ss58(address).unwrap();
+            let (fake_prefix, fake_public) = decode_ss58(&fake).unwrap();
+            assert_eq!(fake_prefix, prefix);
+            assert_eq!(fake_public.len(), public.len());
+        }
+
+        let address = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let fake = anonymize_address(address).unwrap();
+        assert_ne!(fake, address);
+        assert_eq!(
+            checksum_eth_address(&normalize_eth_address(&fake).unwrap()),
+            fake
+        );
+
+        assert!(anonymize_address("not an address").is_err());
+    }
+
+    #[test]
+    fn onchain_account_address() {
+        let alice = alice_sr25519();
+        assert_eq!(
+            print_onchain_account_ss58(&alice, Some(0)),
+            print_multisigner_as_base58(&alice, Some(0))
+        );
+
+        let ecdsa_alice = alice_ecdsa();
+        assert_eq!(
+            print_multisigner_as_base58(&ecdsa_alice, Some(0)),
+            "1CoWvCoktJHtTXSDybnqa1Evg2weWuhRusAiry1dU1fNR2Fy"
+        );
+        assert_eq!(
+            print_onchain_account_ss58(&ecdsa_alice, Some(0)),
+            "13VAtLwNPFNMpqRJ6yzU4cwe3w4eyS9pDaLVW5DFzdvFwWa"
+        );
+    }
+
+    #[test]
+    fn addresses_of_same_network() {
+        assert!(all_same_network(&[]).unwrap());
+        assert!(all_same_network(&[
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "5EYCAe5ijiYfyeZ2JJCGq56LmPyNRAKzpG4QkoQkkQNB5e6Z",
+        ])
+        .unwrap());
+        assert!(!all_same_network(&[
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5",
+        ])
+        .unwrap());
+        assert!(matches!(
+            all_same_network(&[
+                "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+                "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            ]),
+            Err(Error::UnexpectedEthereumAddress)
+        ));
+        assert!(all_same_network(&["not an address"]).is_err());
+    }
+
+    #[test]
+    fn network_genesis_aliases() {
+        let polkadot = H256::from_slice(
+            &hex::decode("91b171bb158e2d3848fa23a9f1c25182fb8e20313b2c1eb49219da7a70ce90c3")
+                .unwrap(),
+        );
+        let aliases = genesis_aliases(&polkadot);
+        assert!(aliases.contains(&polkadot));
+        for alias in aliases {
+            assert_eq!(prefix_for_genesis(alias), Some(0));
+        }
+        assert!(genesis_aliases(&H256([1; 32])).is_empty());
+        assert_eq!(prefix_for_genesis(&H256([1; 32])), None);
+    }
+
+    #[test]
+    fn network_genesis_alias_lookup() {
+        // Polkadot restarted from a new genesis
+        const NETWORKS: [(u16, &[H256]); 2] = [
+            (0, &[POLKADOT_GENESIS_HASH, H256([7; 32])]),
+            (2, &[KUSAMA_GENESIS_HASH]),
+        ];
+        let restarted = H256([7; 32]);
+        assert_eq!(
+            genesis_aliases_in(&NETWORKS, &restarted),
+            &[POLKADOT_GENESIS_HASH, restarted]
+        );
+        assert_eq!(
+            genesis_aliases_in(&NETWORKS, &POLKADOT_GENESIS_HASH),
+            genesis_aliases_in(&NETWORKS, &restarted)
+        );
+        assert_eq!(prefix_for_genesis_in(&NETWORKS, &restarted), Some(0));
+        assert_eq!(
+            prefix_for_genesis_in(&NETWORKS, &KUSAMA_GENESIS_HASH),
+            Some(2)
+        );
+
+        let alice_polkadot = "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5";
+        assert!(address_matches_network_in(&NETWORKS, alice_polkadot, &restarted).unwrap());
+        assert!(
+            !address_matches_network_in(&NETWORKS, alice_polkadot, &KUSAMA_GENESIS_HASH).unwrap()
+        );
+        // restarted genesis is not in the default table
+        assert!(matches!(
+            address_matches_network(alice_polkadot, &restarted),
+            Err(Error::UnknownNetworkGenesisHash(_))
+        ));
+    }
+
+    #[test]
+    fn encryptions_for_payload_len() {
+        assert_eq!(
+            possible_encryptions_for_payload_len(32),
+            vec![Encryption::Ed25519, Encryption::Sr25519]
+        );
+        assert_eq!(
+            possible_encryptions_for_payload_len(33),
+            vec![Encryption::Ecdsa]
+        );
+        assert!(possible_encryptions_for_payload_len(31).is_empty());
+    }
+
+    #[test]
+    fn signer_and_payload_network() {
+        let kusama = H256::from_slice(
+            &hex::decode("b0a8d493285c2df73290dfb7e61f870f17b41801197a149ca93654499ea3dafe")
+                .unwrap(),
+        );
+        assert!(signer_network_matches_payload(2, &kusama));
+        assert!(!signer_network_matches_payload(0, &kusama));
+        assert!(!signer_network_matches_payload(2, &H256([1; 32])));
+    }
+
+    #[test]
+    fn account_indices() {
+        for (index, address) in [(5, "F7cB"), (300, "25XG7v"), (70000, "N8hczkVC")] {
+            assert_eq!(account_index_to_ss58(index, None), address);
+            assert_eq!(parse_account_index(address).unwrap(), index);
+        }
+        assert_eq!(account_index_to_ss58(5, Some(0)), "1RH");
+        assert_eq!(parse_account_index("1RH").unwrap(), 5);
+        assert_eq!(
+            parse_account_index(&account_index_to_ss58(u32::MAX, Some(2))).unwrap(),
+            u32::MAX
+        );
+
+        assert!(matches!(
+            parse_account_index("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+            Err(Error::InvalidAccountIndex)
+        ));
+        assert!(matches!(
+            parse_account_index("F7cC"),
+            Err(Error::Base58Decoding(PublicError::InvalidChecksum))
+        ));
+        assert!(parse_account_index("0OIl").is_err());
+        assert!(matches!(
+            parse_account_index(&"1".repeat(10_000)),
+            Err(Error::InvalidAccountIndex)
+        ));
+    }
+}
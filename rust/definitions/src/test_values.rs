@@ -0,0 +1,24 @@
+//! Keys recurring in unit tests throughout the crate
+
+use sp_core::{ecdsa, sr25519};
+use sp_runtime::MultiSigner;
+
+/// Alice root key, `Sr25519` encryption
+pub(crate) fn alice_sr25519() -> MultiSigner {
+    MultiSigner::Sr25519(sr25519::Public::from_raw(
+        hex::decode("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    ))
+}
+
+/// Alice root key, `Ecdsa` encryption
+pub(crate) fn alice_ecdsa() -> MultiSigner {
+    MultiSigner::Ecdsa(ecdsa::Public::from_raw(
+        hex::decode("020a1091341fe5664bfa1782d5e04779689068c916b04cb365ec3153755684d9a1")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    ))
+}
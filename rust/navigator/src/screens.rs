@@ -12,6 +12,7 @@ use definitions::{
     keyring::{AddressKey, NetworkSpecsKey},
     navigation::{Address, TransactionCardSet},
     network_specs::NetworkSpecs,
+    redacted::Redacted,
     users::AddressDetails,
 };
 use transaction_parsing;
@@ -113,7 +114,7 @@ pub struct RecoverSeedPhraseState {
 ///`EnteredInfo`, `path+pwd` entered by the user, zeroizeable
 #[derive(Debug, Clone, Zeroize)]
 #[zeroize(drop)]
-pub struct EnteredInfo(pub String);
+pub struct EnteredInfo(pub Redacted<String>);
 
 impl KeysState {
     pub fn new(seed_name: &str, database_name: &str) -> Result<Self> {
@@ -345,7 +346,7 @@ impl DeriveState {
         collision: Option<(MultiSigner, AddressDetails)>,
     ) -> Self {
         Self {
-            entered_info: EnteredInfo(entered_string.to_string()),
+            entered_info: EnteredInfo(Redacted::new(entered_string.to_string())),
             keys_state: keys_state.to_owned(),
             collision,
         }
@@ -364,14 +365,14 @@ impl DeriveState {
         self.keys_state.network_specs_key()
     }
     pub fn path(&self) -> String {
-        self.entered_info.0.to_owned()
+        self.entered_info.0.expose().to_owned()
     }
     pub fn collision(&self) -> Option<(MultiSigner, AddressDetails)> {
         self.collision.to_owned()
     }
     pub fn update(&self, new_secret_string: &str) -> Self {
         Self {
-            entered_info: EnteredInfo(new_secret_string.to_string()),
+            entered_info: EnteredInfo(Redacted::new(new_secret_string.to_string())),
             keys_state: self.blank_keys_state(),
             collision: self.collision(),
         }
@@ -392,7 +393,7 @@ impl DeriveState {
 impl TransactionState {
     pub fn new(details_str: &str, dbname: &str) -> Self {
         Self {
-            entered_info: EnteredInfo("".to_string()),
+            entered_info: EnteredInfo(Redacted::new("".to_string())),
             action: transaction_parsing::produce_output(details_str, dbname),
             comment: "".to_string(),
             counter: 1,
@@ -400,7 +401,7 @@ impl TransactionState {
     }
     pub fn update_seed(&self, new_secret_string: &str) -> Self {
         Self {
-            entered_info: EnteredInfo(new_secret_string.to_string()),
+            entered_info: EnteredInfo(Redacted::new(new_secret_string.to_string())),
             action: self.action(),
             comment: self.comment.to_string(),
             counter: self.counter,
@@ -440,7 +441,7 @@ impl TransactionState {
         self.action.to_owned()
     }
     pub fn seed(&self) -> String {
-        self.entered_info.0.to_string()
+        self.entered_info.0.expose().to_string()
     }
     pub fn get_comment(&self) -> String {
         self.comment.to_owned()
@@ -457,7 +458,7 @@ impl SufficientCryptoState {
     pub fn init(content: transaction_signing::SufficientContent) -> Self {
         Self {
             key_selected: None,
-            entered_info: EnteredInfo("".to_string()),
+            entered_info: EnteredInfo(Redacted::new("".to_string())),
             content,
             counter: 1,
         }
@@ -490,13 +491,13 @@ impl SufficientCryptoState {
                 address_details.to_owned(),
                 author_info,
             )),
-            entered_info: EnteredInfo(new_secret_string.to_string()),
+            entered_info: EnteredInfo(Redacted::new(new_secret_string.to_string())),
             content: self.content(),
             counter: self.counter,
         }
     }
     pub fn seed(&self) -> String {
-        self.entered_info.0.to_string()
+        self.entered_info.0.expose().to_string()
     }
     pub fn plus_one(&self) -> Self {
         Self {
@@ -657,6 +658,7 @@ mod tests {
                 sufficient_crypto_test_updated
                     .entered_info
                     .0
+                    .expose()
                     .as_ptr()
                     .offset(-100)
             };
@@ -1,5 +1,6 @@
 use crate::error::{Error, Result};
 use definitions::{
+    crypto::Encryption,
     error::TransferContent,
     helpers::unhex,
     network_specs::{Verifier, VerifierValue},
@@ -25,11 +26,12 @@ pub fn pass_crypto(data_hex: &str, content: TransferContent) -> Result<InfoPasse
             let into_pubkey: [u8; 32] = a.try_into().expect("fixed size should fit in array");
             let (pubkey, data) = (ed25519::Public::from_raw(into_pubkey), &data[35..]);
             let (message, tail) = cut_data(data, content)?;
-            let a = tail.get(..64).ok_or(Error::TooShort)?;
+            let signature_len = Encryption::Ed25519.signature_len();
+            let a = tail.get(..signature_len).ok_or(Error::TooShort)?;
             let into_signature: [u8; 64] = a.try_into().expect("fixed size should fit in array");
             let (signature, tail) = (
                 ed25519::Signature::from_raw(into_signature),
-                tail[64..].to_vec(),
+                tail[signature_len..].to_vec(),
             );
             ed25519::Pair::verify(&signature, &message, &pubkey)
                 .then_some(())
@@ -51,11 +53,12 @@ pub fn pass_crypto(data_hex: &str, content: TransferContent) -> Result<InfoPasse
             let into_pubkey: [u8; 32] = a.try_into().expect("fixed size should fit in array");
             let (pubkey, data) = (sr25519::Public::from_raw(into_pubkey), &data[35..]);
             let (message, tail) = cut_data(data, content)?;
-            let a = tail.get(..64).ok_or(Error::TooShort)?;
+            let signature_len = Encryption::Sr25519.signature_len();
+            let a = tail.get(..signature_len).ok_or(Error::TooShort)?;
             let into_signature: [u8; 64] = a.try_into().expect("fixed size should fit in array");
             let (signature, tail) = (
                 sr25519::Signature::from_raw(into_signature),
-                tail[64..].to_vec(),
+                tail[signature_len..].to_vec(),
             );
             sr25519::Pair::verify(&signature, &message, &pubkey)
                 .then_some(())
@@ -77,11 +80,12 @@ pub fn pass_crypto(data_hex: &str, content: TransferContent) -> Result<InfoPasse
             let into_pubkey: [u8; 33] = a.try_into().expect("fixed size should fit in array");
             let (pubkey, data) = (ecdsa::Public::from_raw(into_pubkey), &data[36..]);
             let (message, tail) = cut_data(data, content)?;
-            let a = tail.get(..65).ok_or(Error::TooShort)?;
+            let signature_len = Encryption::Ecdsa.signature_len();
+            let a = tail.get(..signature_len).ok_or(Error::TooShort)?;
             let into_signature: [u8; 65] = a.try_into().expect("fixed size should fit in array");
             let (signature, tail) = (
                 ecdsa::Signature::from_raw(into_signature),
-                tail[65..].to_vec(),
+                tail[signature_len..].to_vec(),
             );
             ecdsa::Pair::verify(&signature, &message, &pubkey)
                 .then_some(())